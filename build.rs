@@ -0,0 +1,10 @@
+// proto/memo.proto 에서 gRPC 서버/클라이언트 코드 생성
+// protoc 은 protoc-bin-vendored 에 들어 있는 것을 쓰므로 따로 설치하지 않아도 된다
+// (PROTOC 를 지정하면 그 protoc 를 쓴다)
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::var_os("PROTOC").is_none() {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+    tonic_build::compile_protos("proto/memo.proto")?;
+    Ok(())
+}
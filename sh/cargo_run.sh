@@ -3,3 +3,18 @@ cargo run --bin spreadsheet_create
 # cargo run --bin main
 # cargo run --bin gui
 # cargo run --bin mouse_move
+# cargo run --release --bin memo_bench
+# cargo run --bin main -- verify-audit audit.log
+# cargo run --bin memo -- list
+# cargo test --test memo_api
+# bash sh/api_test.sh   (서버를 띄운 뒤 curl 로 CRUD 확인)
+# cargo test --test memo_grpc
+# grpcurl -plaintext -import-path proto -proto memo.proto localhost:50051 memo.v1.MemoService/ListMemos
+# cargo test --test memo_sync
+# cargo test --test sheets
+# cargo run --bin sheets_standin
+# cargo test --test sheets_standin
+# cargo test --test sheet_row
+# cargo test --test helpers -- --nocapture
+# WORKBOOK_SCHEMA=schemas/project_workbook.toml cargo run --bin spreadsheet_create
+# cargo run --bin spreadsheet_create -- --plan
@@ -0,0 +1,119 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, LitStr};
+
+// #[derive(SheetRow)] - 구조체 필드를 시트 열로 (hello::sheets::SheetRow 구현)
+// 헤더 목록, 행 쓰기(to_row), 행 읽기(from_row) 를 만든다
+// 필드 속성
+// - #[sheet(rename = "Project_ID")]  헤더 이름 (없으면 필드 이름의 단어마다 첫 글자를 대문자로: task_name → Task_Name)
+// - #[sheet(order = 0)]             열 순서 (없으면 선언 순서 번호, 같으면 선언 순서대로)
+// - #[sheet(format = "%Y/%m/%d")]   날짜 형식 (쓸 때 이 형식으로, 읽을 때도 이 형식을 먼저 시도)
+// 구조체 속성
+// - #[sheet_row(crate = "crate")]   hello 크레이트 경로 (기본 ::hello, hello 크레이트 안이나 다른 이름으로 가져왔을 때)
+#[proc_macro_derive(SheetRow, attributes(sheet, sheet_row))]
+pub fn derive_sheet_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+struct Column {
+    ident: syn::Ident,
+    header: String,
+    order: usize,
+    format: Option<LitStr>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "SheetRow 는 이름 있는 필드의 구조체에만 쓸 수 있습니다")),
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "SheetRow 는 구조체에만 쓸 수 있습니다")),
+    };
+
+    let mut krate: syn::Path = syn::parse_quote!(::hello);
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("sheet_row")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("지원하지 않는 sheet_row 속성입니다 (crate)"))
+            }
+        })?;
+    }
+
+    let mut columns = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let ident = field.ident.clone().expect("이름 있는 필드");
+        let mut column = Column { header: default_header(&ident.unraw().to_string()), ident, order: index, format: None };
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("sheet")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    column.header = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("order") {
+                    column.order = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                } else if meta.path.is_ident("format") {
+                    column.format = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("지원하지 않는 sheet 속성입니다 (rename, order, format)"));
+                }
+                Ok(())
+            })?;
+        }
+
+        if let Some(other) = columns.iter().find(|other: &&Column| other.header == column.header) {
+            return Err(syn::Error::new_spanned(&column.ident, format!("헤더 \"{}\" 가 {} 필드와 겹칩니다", column.header, other.ident)));
+        }
+        columns.push(column);
+    }
+    // 정렬은 안정적이라 order 가 같으면 선언 순서
+    columns.sort_by_key(|column| column.order);
+
+    let headers = columns.iter().map(|column| &column.header);
+    let to_cells = columns.iter().map(|Column { ident, format, .. }| match format {
+        Some(format) => quote! { #krate::sheets::FormatCell::format_cell(&self.#ident, #format) },
+        None => quote! { #krate::sheets::ToCell::to_cell(&self.#ident) },
+    });
+    let from_cells = columns.iter().map(|Column { ident, header, format, .. }| {
+        let parse = match format {
+            Some(format) => quote! { |value| #krate::sheets::FormatCell::parse_cell(value, #format) },
+            None => quote! { #krate::sheets::FromCell::from_cell },
+        };
+        quote! { #ident: #krate::sheets::parse_row_cell(headers, cells, #header, #parse)? }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::sheets::SheetRow for #name #ty_generics #where_clause {
+            const HEADERS: &'static [&'static str] = &[#(#headers),*];
+
+            fn to_row(&self) -> ::std::vec::Vec<::std::string::String> {
+                ::std::vec![#(#to_cells),*]
+            }
+
+            fn from_row(headers: &[::std::string::String], cells: &[::std::string::String]) -> ::std::result::Result<Self, #krate::sheets::FieldError> {
+                ::std::result::Result::Ok(Self { #(#from_cells),* })
+            }
+        }
+    })
+}
+
+// task_name → Task_Name
+fn default_header(field: &str) -> String {
+    field
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
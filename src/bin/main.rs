@@ -1,129 +1,75 @@
-use actix_web::{web, App, HttpResponse, HttpServer, Responder};
-use serde::{Deserialize, Serialize};
-use std::sync::Mutex;
-use std::collections::HashMap;
+use actix_web::{web, App, HttpServer};
+use hello::memo::{self, AppState, AuditLog, ContentCipher};
 
-// 메모 구조체 정의
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct Memo {
-    id: u64,
-    title: String,
-    content: String,
-    created_at: chrono::DateTime<chrono::Utc>,
-}
+// 감사 로그 파일 검증 CLI
+//   cargo run --bin main -- verify-audit audit.log
+fn verify_audit_command(path: &str) -> std::io::Result<()> {
+    let entries = match memo::read_audit_file(path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("감사 로그를 읽을 수 없습니다: {}", e);
+            std::process::exit(2);
+        }
+    };
 
-// 메모 생성을 위한 요청 구조체
-#[derive(Debug, Deserialize)]
-struct CreateMemoRequest {
-    title: String,
-    content: String,
+    match memo::verify_audit_chain(&entries) {
+        Ok(()) => {
+            println!("감사 로그 정상: {}개 항목", entries.len());
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("감사 로그 체인이 깨졌습니다: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
 
-// 애플리케이션 상태를 저장할 구조체
-struct AppState {
-    memos: Mutex<HashMap<u64, Memo>>,
-    counter: Mutex<u64>,
-}
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("verify-audit") {
+        let path = args.get(2).cloned().or_else(|| std::env::var("AUDIT_LOG_PATH").ok()).unwrap_or_else(|| "audit.log".to_string());
+        return verify_audit_command(&path);
+    }
 
-// CREATE - 새 메모 생성
-async fn create_memo(
-    data: web::Data<AppState>,
-    memo_req: web::Json<CreateMemoRequest>,
-) -> impl Responder {
-    let mut counter = data.counter.lock().unwrap();
-    let mut memos = data.memos.lock().unwrap();
-    
-    let memo = Memo {
-        id: *counter,
-        title: memo_req.title.clone(),
-        content: memo_req.content.clone(),
-        created_at: chrono::Utc::now(),
-    };
-    
-    memos.insert(*counter, memo.clone());
-    *counter += 1;
-    
-    HttpResponse::Ok().json(memo)
-}
+    // .env 파일 로드 (암호화 키 등)
+    dotenv::dotenv().ok();
 
-// READ - 모든 메모 조회
-async fn get_memos(data: web::Data<AppState>) -> impl Responder {
-    let memos = data.memos.lock().unwrap();
-    let memo_list: Vec<&Memo> = memos.values().collect();
-    HttpResponse::Ok().json(memo_list)
-}
+    // 감사 로그 파일 열기 (AUDIT_LOG_PATH, 기본 audit.log - verify-audit 로 검증)
+    let audit_path = std::env::var("AUDIT_LOG_PATH").unwrap_or_else(|_| "audit.log".to_string());
+    let audit = AuditLog::open(&audit_path).map_err(|e| std::io::Error::other(e.to_string()))?;
 
-// READ - 특정 메모 조회
-async fn get_memo(
-    data: web::Data<AppState>,
-    id: web::Path<u64>,
-) -> impl Responder {
-    let memos = data.memos.lock().unwrap();
-    
-    match memos.get(&id.into_inner()) {
-        Some(memo) => HttpResponse::Ok().json(memo),
-        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
-    }
-}
+    // 본문 암호화 키 읽기
+    let cipher = ContentCipher::from_env().map_err(std::io::Error::other)?;
 
-// UPDATE - 메모 수정
-async fn update_memo(
-    data: web::Data<AppState>,
-    id: web::Path<u64>,
-    memo_req: web::Json<CreateMemoRequest>,
-) -> impl Responder {
-    let mut memos = data.memos.lock().unwrap();
-    
-    match memos.get_mut(&id.into_inner()) {
-        Some(memo) => {
-            memo.title = memo_req.title.clone();
-            memo.content = memo_req.content.clone();
-            HttpResponse::Ok().json(memo)
-        }
-        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
-    }
-}
+    // 애플리케이션 상태 초기화 (MEMO_ADMIN_TOKEN 이 있으면 관리자 API 를 연다)
+    let app_state = web::Data::new(AppState::new(audit, cipher).with_admin_token(std::env::var("MEMO_ADMIN_TOKEN").ok()));
 
-// DELETE - 메모 삭제
-async fn delete_memo(
-    data: web::Data<AppState>,
-    id: web::Path<u64>,
-) -> impl Responder {
-    let mut memos = data.memos.lock().unwrap();
-    
-    match memos.remove(&id.into_inner()) {
-        Some(_) => HttpResponse::Ok().body("메모가 삭제되었습니다"),
-        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
-    }
-}
+    // 알림 스케줄러 시작
+    actix_web::rt::spawn(memo::run_reminder_scheduler(app_state.clone()));
+    // 이전 키로 저장된 본문 재암호화 (암호화 설정이 없으면 바로 끝난다, 실행 중 교체는 POST /admin/rotate-keys)
+    actix_web::rt::spawn(memo::run_key_rotation(app_state.clone()));
+    // 만료된 메모 정리 시작
+    actix_web::rt::spawn(memo::run_expiry_sweeper(app_state.clone()));
 
-#[actix_web::main]
-async fn main() -> std::io::Result<()> {
-    // 애플리케이션 상태 초기화
-    let app_state = web::Data::new(AppState {
-        memos: Mutex::new(HashMap::new()),
-        counter: Mutex::new(0),
-    });
+    // 시트 동기화 시작 (MEMO_SYNC_SPREADSHEET_ID 가 없거나 본문 암호화를 켰으면 바로 끝난다)
+    actix_web::rt::spawn(memo::run_sheet_sync(app_state.clone()));
+
+    // gRPC 서버 시작 (MEMO_GRPC_ADDR, 기본 127.0.0.1:50051)
+    let grpc_addr = std::env::var("MEMO_GRPC_ADDR").unwrap_or_else(|_| "127.0.0.1:50051".to_string());
+    let grpc_addr = grpc_addr.parse::<std::net::SocketAddr>().map_err(|e| std::io::Error::other(format!("MEMO_GRPC_ADDR 를 읽을 수 없습니다: {}", e)))?;
+    actix_web::rt::spawn(memo::serve_grpc(app_state.clone(), grpc_addr));
 
-    println!("서버가 http://localhost:8080 에서 실행 중입니다");
+    println!("서버가 http://localhost:8080 에서 실행 중입니다 (gRPC: {})", grpc_addr);
 
     // 서버 실행
-    HttpServer::new(move || {
-        App::new()
-            .app_data(app_state.clone())
-            .route("/memos", web::post().to(create_memo))
-            .route("/memos", web::get().to(get_memos))
-            .route("/memos/{id}", web::get().to(get_memo))
-            .route("/memos/{id}", web::put().to(update_memo))
-            .route("/memos/{id}", web::delete().to(delete_memo))
-    })
-    .bind("127.0.0.1:8080")?
-    .run()
-    .await
-}
+    let server = HttpServer::new(move || App::new().app_data(app_state.clone()).configure(memo::configure_routes));
 
-/* 결과
-Hello, world!
-mod_string::function()
-mod_number::add_number() : 5
-*/
\ No newline at end of file
+    // MEMO_WORKERS 가 있으면 워커 수를 고정 (벤치마크용), 없으면 CPU 코어 수
+    let server = match std::env::var("MEMO_WORKERS").ok().and_then(|w| w.parse::<usize>().ok()) {
+        Some(workers) => server.workers(workers),
+        None => server,
+    };
+
+    server.bind("127.0.0.1:8080")?.run().await
+}
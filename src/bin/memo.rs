@@ -0,0 +1,237 @@
+use hello::memo_cli::{parse_options, slugify, truncate, CliResult, Options, USAGE};
+use hyper::{Body, Client, Method, Request};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::Command;
+
+// 메모 서버 명령줄 클라이언트
+//   cargo run --bin memo -- list
+//   cargo run --bin memo -- --json get 3
+//   cargo run --bin memo -- new "회의록"
+//   cargo run --bin memo -- export --format md --out ./memos
+//
+// 설정 (옵션이 환경변수보다 우선)
+// - --url / MEMO_URL: 서버 주소 (기본 http://localhost:8080)
+// - --token / MEMO_TOKEN: Authorization: Bearer 로 보낼 토큰
+// - --json: 표 대신 JSON 으로 출력
+
+// 서버가 내려주는 메모 (수정할 때 나머지 필드를 그대로 돌려보내기 위해 모두 받는다)
+#[derive(Debug, Serialize, Deserialize)]
+struct Memo {
+    id: u64,
+    title: String,
+    content: String,
+    created_at: String,
+    due_at: Option<String>,
+    remind_at: Option<String>,
+    recurrence: Option<String>,
+    expires_at: Option<String>,
+    notebook_id: Option<u64>,
+}
+
+// 메모 API 클라이언트
+struct ApiClient {
+    base_url: String,
+    token: Option<String>,
+    client: Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>,
+}
+
+impl ApiClient {
+    fn new(base_url: &str, token: Option<String>) -> Self {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new().with_native_roots().https_or_http().enable_http1().build();
+        ApiClient { base_url: base_url.to_string(), token, client: Client::builder().build(connector) }
+    }
+
+    // 요청을 보내고 2xx 가 아니면 서버가 준 메시지로 에러를 만든다
+    async fn request(&self, method: Method, path: &str, body: Option<Value>) -> CliResult<Vec<u8>> {
+        let mut req = Request::builder().method(method).uri(format!("{}{}", self.base_url, path));
+
+        // 감사 로그의 actor 로 남도록 사용자 이름을 보낸다
+        if let Ok(user) = std::env::var("USER") {
+            req = req.header("X-User", user);
+        }
+        if let Some(token) = &self.token {
+            req = req.header("Authorization", format!("Bearer {}", token));
+        }
+
+        let req = match body {
+            Some(body) => req.header("Content-Type", "application/json").body(Body::from(serde_json::to_vec(&body)?))?,
+            None => req.body(Body::empty())?,
+        };
+
+        let res = self.client.request(req).await?;
+        let status = res.status();
+        let bytes = hyper::body::to_bytes(res.into_body()).await?.to_vec();
+
+        if !status.is_success() {
+            return Err(format!("{}: {}", status, String::from_utf8_lossy(&bytes)).into());
+        }
+        Ok(bytes)
+    }
+
+    async fn list(&self, q: Option<&str>) -> CliResult<Vec<Memo>> {
+        let path = match q {
+            Some(q) => format!("/memos?q={}", utf8_percent_encode(q, NON_ALPHANUMERIC)),
+            None => "/memos".to_string(),
+        };
+        Ok(serde_json::from_slice(&self.request(Method::GET, &path, None).await?)?)
+    }
+
+    async fn get(&self, id: u64) -> CliResult<Memo> {
+        Ok(serde_json::from_slice(&self.request(Method::GET, &format!("/memos/{}", id), None).await?)?)
+    }
+
+    async fn create(&self, title: &str, content: &str) -> CliResult<Memo> {
+        let body = json!({ "title": title, "content": content });
+        Ok(serde_json::from_slice(&self.request(Method::POST, "/memos", Some(body)).await?)?)
+    }
+
+    // 본문만 바꾸고 나머지 필드는 그대로 보낸다 (PUT 은 전체 교체)
+    async fn update_content(&self, memo: &Memo, content: &str) -> CliResult<Memo> {
+        let body = json!({ "title": memo.title, "content": content, "due_at": memo.due_at, "remind_at": memo.remind_at, "recurrence": memo.recurrence, "expires_at": memo.expires_at, "notebook_id": memo.notebook_id });
+        Ok(serde_json::from_slice(&self.request(Method::PUT, &format!("/memos/{}", memo.id), Some(body)).await?)?)
+    }
+
+    async fn delete(&self, id: u64) -> CliResult<()> {
+        self.request(Method::DELETE, &format!("/memos/{}", id), None).await?;
+        Ok(())
+    }
+}
+
+// $EDITOR 로 임시 파일을 열어 편집한 내용을 돌려준다
+fn edit_in_editor(initial: &str) -> CliResult<String> {
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    // 이름을 추측할 수 없는 파일을 새로 만든다 (미리 만들어 둔 심볼릭 링크를 따라가지 않는다, 권한 0600)
+    let mut file = tempfile::Builder::new().prefix("memo-").suffix(".md").tempfile()?;
+    file.write_all(initial.as_bytes())?;
+    let path = file.path().to_path_buf();
+
+    // "code --wait" 처럼 인자가 붙은 편집기도 지원
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().ok_or("EDITOR 가 비어 있습니다")?;
+    let status = Command::new(program).args(parts).arg(&path).status().map_err(|e| format!("편집기 {} 를 실행할 수 없습니다: {}", editor, e))?;
+    // 편집기가 새 파일로 바꿔치기할 수 있으므로 경로로 다시 읽는다 (파일은 file 을 놓을 때 지워진다)
+    let content = std::fs::read_to_string(&path);
+    drop(file);
+
+    if !status.success() {
+        return Err(format!("편집기가 비정상 종료했습니다: {}", status).into());
+    }
+    Ok(content?)
+}
+
+// 메모 목록을 표로 출력
+fn print_table(memos: &[Memo]) {
+    println!("{:>5}  {:<30}  {:<25}  내용", "ID", "제목", "생성");
+    for memo in memos {
+        println!("{:>5}  {:<30}  {:<25}  {}", memo.id, truncate(&memo.title, 30), memo.created_at.get(..19).unwrap_or(&memo.created_at), truncate(&memo.content, 40));
+    }
+}
+
+// 메모 하나 출력
+fn print_memo(memo: &Memo) {
+    println!("#{} {}", memo.id, memo.title);
+    println!("생성: {}", memo.created_at);
+    if let Some(due_at) = &memo.due_at {
+        println!("마감: {}", due_at);
+    }
+    println!();
+    println!("{}", memo.content);
+}
+
+// 전체 메모 내보내기
+async fn export(api: &ApiClient, options: &Options) -> CliResult<()> {
+    let bytes = api.request(Method::GET, "/memos", None).await?;
+
+    match options.format.as_str() {
+        "json" => {
+            // 서버 응답을 그대로 보기 좋게 저장
+            let memos: Value = serde_json::from_slice(&bytes)?;
+            let text = serde_json::to_string_pretty(&memos)?;
+            match &options.out {
+                Some(path) => std::fs::write(path, text)?,
+                None => println!("{}", text),
+            }
+        }
+        "md" => {
+            let memos: Vec<Memo> = serde_json::from_slice(&bytes)?;
+            let dir = options.out.clone().unwrap_or_else(|| "memos".to_string());
+            std::fs::create_dir_all(&dir)?;
+            for memo in &memos {
+                let path = std::path::Path::new(&dir).join(format!("{}-{}.md", memo.id, slugify(&memo.title)));
+                std::fs::write(path, format!("# {}\n\n{}\n", memo.title, memo.content))?;
+            }
+            println!("메모 {}개를 {} 에 내보냈습니다", memos.len(), dir);
+        }
+        other => return Err(format!("지원하지 않는 형식입니다: {} (json, md)", other).into()),
+    }
+
+    Ok(())
+}
+
+// 명령 실행
+async fn run(options: Options) -> CliResult<()> {
+    let api = ApiClient::new(&options.url, options.token.clone());
+    let args: Vec<&str> = options.args.iter().map(String::as_str).collect();
+    let id = |arg: Option<&&str>| -> CliResult<u64> { arg.ok_or("메모 ID 가 필요합니다")?.parse::<u64>().map_err(|_| "메모 ID 는 숫자여야 합니다".into()) };
+
+    match args.first().copied() {
+        Some("list") | Some("search") => {
+            let q = if args[0] == "search" { Some(args.get(1).ok_or("검색어가 필요합니다")?.to_string()) } else { None };
+            let memos = api.list(q.as_deref()).await?;
+            if options.json {
+                println!("{}", serde_json::to_string_pretty(&memos)?);
+            } else {
+                print_table(&memos);
+            }
+        }
+        Some("get") => {
+            let memo = api.get(id(args.get(1))?).await?;
+            if options.json {
+                println!("{}", serde_json::to_string_pretty(&memo)?);
+            } else {
+                print_memo(&memo);
+            }
+        }
+        Some("new") => {
+            let title = args.get(1).ok_or("제목이 필요합니다")?;
+            let content = edit_in_editor("")?;
+            let memo = api.create(title, content.trim_end()).await?;
+            println!("메모 {} 를 만들었습니다", memo.id);
+        }
+        Some("edit") => {
+            let memo = api.get(id(args.get(1))?).await?;
+            let content = edit_in_editor(&memo.content)?;
+            if content.trim_end() == memo.content.trim_end() {
+                println!("변경 사항이 없습니다");
+                return Ok(());
+            }
+            api.update_content(&memo, content.trim_end()).await?;
+            println!("메모 {} 를 수정했습니다", memo.id);
+        }
+        Some("rm") => {
+            let id = id(args.get(1))?;
+            api.delete(id).await?;
+            println!("메모 {} 를 삭제했습니다", id);
+        }
+        Some("export") => export(&api, &options).await?,
+        _ => return Err(USAGE.into()),
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    let result = match parse_options(std::env::args().skip(1).collect()) {
+        Ok(options) => run(options).await,
+        Err(e) => Err(e),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
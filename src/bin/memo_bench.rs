@@ -0,0 +1,107 @@
+use hyper::{Body, Client, Method, Request};
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// 메모 서버 읽기/쓰기 혼합 부하 벤치마크
+// 서버를 먼저 띄운 뒤 실행한다 (MEMO_WORKERS 로 서버 워커 수를 바꿔가며 비교)
+//   MEMO_WORKERS=4 cargo run --release --bin main
+//   cargo run --release --bin memo_bench
+
+// 동시 클라이언트 수
+const WORKER_COUNTS: [usize; 5] = [1, 2, 4, 8, 16];
+// 읽기 비율 (%)
+const READ_RATIOS: [u64; 3] = [50, 90, 99];
+// 미리 만들어 둘 메모 수
+const SEED_MEMOS: u64 = 100;
+
+// 메모 하나 생성하고 서버가 정한 ID 를 돌려준다 (2xx 가 아니면 에러)
+async fn create(client: &Client<hyper::client::HttpConnector>, base_url: &str, i: u64) -> Result<u64, Box<dyn Error + Send + Sync>> {
+    let body = format!("{{\"title\": \"bench {}\", \"content\": \"benchmark memo\"}}", i);
+    let req = Request::builder().method(Method::POST).uri(format!("{}/memos", base_url)).header("Content-Type", "application/json").body(Body::from(body))?;
+    let res = client.request(req).await?;
+    let status = res.status();
+    let body = hyper::body::to_bytes(res.into_body()).await?;
+    if !status.is_success() {
+        return Err(format!("메모 생성 실패: {}", status).into());
+    }
+    let memo: serde_json::Value = serde_json::from_slice(&body)?;
+    memo["id"].as_u64().ok_or_else(|| "응답에 메모 ID 가 없습니다".into())
+}
+
+// 메모 하나 조회 (2xx 가 아니면 에러)
+async fn read(client: &Client<hyper::client::HttpConnector>, base_url: &str, id: u64) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let res = client.get(format!("{}/memos/{}", base_url, id).parse()?).await?;
+    let status = res.status();
+    hyper::body::to_bytes(res.into_body()).await?;
+    if !status.is_success() {
+        return Err(format!("메모 조회 실패: {}", status).into());
+    }
+    Ok(())
+}
+
+// 주어진 동시성과 읽기 비율로 duration 동안 요청을 보내고 초당 처리량을 반환
+// 읽기는 미리 만든 메모(seeded)만 대상으로 하고, 성공(2xx)한 요청만 센다
+async fn run(base_url: &str, seeded: Arc<Vec<u64>>, workers: usize, read_ratio: u64, duration: Duration) -> f64 {
+    let client = Client::new();
+    let ops = Arc::new(AtomicU64::new(0));
+    let deadline = Instant::now() + duration;
+
+    let handles: Vec<_> = (0..workers)
+        .map(|w| {
+            let client = client.clone();
+            let ops = ops.clone();
+            let base_url = base_url.to_string();
+            let seeded = seeded.clone();
+            tokio::spawn(async move {
+                let mut i = w as u64;
+                while Instant::now() < deadline {
+                    // 난수 대신 카운터로 읽기/쓰기를 섞는다
+                    let result = if i % 100 < read_ratio {
+                        read(&client, &base_url, seeded[(i % seeded.len() as u64) as usize]).await
+                    } else {
+                        create(&client, &base_url, i).await.map(|_| ())
+                    };
+                    if result.is_ok() {
+                        ops.fetch_add(1, Ordering::Relaxed);
+                    }
+                    i += workers as u64;
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.await.ok();
+    }
+
+    ops.load(Ordering::Relaxed) as f64 / duration.as_secs_f64()
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let base_url = std::env::var("MEMO_BENCH_URL").unwrap_or_else(|_| "http://127.0.0.1:8080".to_string());
+    let seconds = std::env::var("MEMO_BENCH_SECONDS").ok().and_then(|s| s.parse().ok()).unwrap_or(3);
+    let duration = Duration::from_secs(seconds);
+
+    // 조회할 메모 미리 생성 (서버에 메모가 이미 있어도 되도록 응답의 ID 를 모아 둔다)
+    let client = Client::new();
+    let mut seeded = Vec::new();
+    for i in 0..SEED_MEMOS {
+        seeded.push(create(&client, &base_url, i).await?);
+    }
+    let seeded = Arc::new(seeded);
+
+    println!("대상: {} ({}초씩 측정)", base_url, seconds);
+    println!("{:>8} {:>8} {:>12}", "workers", "read%", "req/s");
+
+    for read_ratio in READ_RATIOS {
+        for workers in WORKER_COUNTS {
+            let throughput = run(&base_url, seeded.clone(), workers, read_ratio, duration).await;
+            println!("{:>8} {:>8} {:>12.0}", workers, read_ratio, throughput);
+        }
+    }
+
+    Ok(())
+}
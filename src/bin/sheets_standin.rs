@@ -0,0 +1,20 @@
+use actix_web::{web, App, HttpServer};
+use hello::sheets::{configure_standin_routes, InMemorySheets, SheetsBackend};
+
+// 구글 Sheets API 대역 서버 - 구글 자격 증명 없이 시트 바이너리를 끝까지 실행해 볼 때 사용
+//   cargo run --bin sheets_standin
+//   SHEETS_BASE_URL=http://127.0.0.1:8090/ GOOGLE_SERVICE_ACCOUNT_KEY=tests/fixtures/standin_service_account.json SPREADSHEET_ID=spreadsheet-1 cargo run --bin spreadsheet
+// 키 파일의 token_uri 가 127.0.0.1:8090 이므로 주소를 바꾸면 키 파일도 고쳐야 한다
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let addr = std::env::var("STANDIN_ADDR").unwrap_or_else(|_| "127.0.0.1:8090".to_string());
+
+    // 바이너리들이 바로 쓸 수 있도록 spreadsheet-1 과 test_sheet 시트를 만들어 둔다
+    let sheets = InMemorySheets::new();
+    let spreadsheet_id = sheets.create_spreadsheet("standin").await.map_err(std::io::Error::other)?;
+    sheets.add_sheet(&spreadsheet_id, "test_sheet").await.map_err(std::io::Error::other)?;
+
+    println!("Sheets API 대역 서버가 http://{} 에서 실행 중입니다 (스프레드시트 ID: {})", addr, spreadsheet_id);
+
+    HttpServer::new(move || App::new().app_data(web::Data::new(sheets.clone())).configure(configure_standin_routes)).bind(addr)?.run().await
+}
@@ -1,5 +1,7 @@
 // pub으로 선언하면 main에서 helpers 직접 접근 가능
 mod mod_number;
+// 처음 작성한 예제 코드를 그대로 둔다 (println!("{}", "...") 형태)
+#[allow(clippy::print_literal)]
 mod mod_string;
 
 pub fn function_string() {
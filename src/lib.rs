@@ -0,0 +1,10 @@
+// 라이브러리 크레이트 - 모든 바이너리(src/bin)와 tests/ 에서 hello::... 로 사용
+// - helpers: 공용 도우미 함수
+// - memo: 메모 서버 (다른 actix 앱에 경로 접두사를 붙여 마운트할 수 있다)
+//     App::new().app_data(state).service(web::scope("/memo-api").configure(memo::configure_routes))
+// - memo_cli: 메모 명령줄 클라이언트(src/bin/memo.rs)의 옵션 파싱과 출력 도우미
+// - sheets: 구글 시트 클라이언트 (한 번 인증해서 재사용)
+pub mod helpers;
+pub mod memo;
+pub mod memo_cli;
+pub mod sheets;
@@ -0,0 +1,364 @@
+// 메모 서버 - 상태, 핸들러, 라우트 (src/bin/main.rs 와 tests/ 에서 사용)
+mod audit;
+mod crypto;
+mod expiry;
+mod graphql;
+mod grpc;
+mod handlers;
+mod links;
+mod markdown;
+mod notebook;
+mod reminder;
+mod sheet_sync;
+mod template;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use tokio::sync::broadcast;
+
+pub use audit::{read_audit_file, verify_audit_chain, AuditAction, AuditEntry, AuditLog};
+pub use crypto::{run_key_rotation, ContentCipher};
+pub use expiry::{run_expiry_sweeper, sweep_expired};
+pub use graphql::{schema as graphql_schema, MemoSchema};
+pub use grpc::{grpc_service, pb, serve_grpc, MemoGrpc};
+pub use handlers::configure_routes;
+pub use reminder::run_reminder_scheduler;
+pub use sheet_sync::{run_sheet_sync, sync_sheet, GoogleSheet, MemorySheet, SheetStore, SyncError, SyncReport};
+
+use audit::Actor;
+use links::parse_wiki_links;
+use notebook::Notebook;
+use reminder::Reminder;
+use template::MemoTemplate;
+
+// 알림 반복 규칙
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Recurrence {
+    Daily,
+    Weekly,
+}
+
+impl Recurrence {
+    // 한 번 반복할 때 더해지는 시간
+    fn interval(&self) -> chrono::Duration {
+        match self {
+            Recurrence::Daily => chrono::Duration::days(1),
+            Recurrence::Weekly => chrono::Duration::weeks(1),
+        }
+    }
+}
+
+// 메모 구조체 정의
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Memo {
+    pub id: u64,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    // 제목/본문/일정을 마지막으로 수정한 시각 (노트북 이동, 알림 발송은 포함하지 않는다)
+    pub updated_at: DateTime<Utc>,
+    // 할 일 마감 시각
+    pub due_at: Option<DateTime<Utc>>,
+    // 다음 알림 시각 (알림이 나가면 반복 규칙에 따라 밀리거나 비워진다)
+    pub remind_at: Option<DateTime<Utc>>,
+    pub recurrence: Option<Recurrence>,
+    // 메모가 들어 있는 노트북 (None 이면 최상위)
+    pub notebook_id: Option<u64>,
+    // 이 시각이 지나면 조회에서 빠지고 스위퍼가 지운다 (None 이면 만료 없음)
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Memo {
+    // 만료 시각이 지났는지 (스위퍼가 아직 지우지 않았어도 없는 메모로 본다)
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+}
+
+// 메모 생성을 위한 요청 구조체
+#[derive(Debug, Deserialize)]
+pub struct CreateMemoRequest {
+    pub title: String,
+    pub content: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub recurrence: Option<Recurrence>,
+    // 수정할 때 없으면 지금 노트북 유지 (최상위로 옮기려면 PUT /memos/{id}/notebook)
+    pub notebook_id: Option<u64>,
+    // 만료 시각 또는 지금부터 몇 초 뒤 만료 (둘 중 하나만, 수정할 때 둘 다 없으면 지금 만료 시각 유지)
+    pub expires_at: Option<DateTime<Utc>>,
+    pub ttl_seconds: Option<u64>,
+}
+
+impl CreateMemoRequest {
+    // 요청의 만료 시각 (둘 다 주었거나 이미 지난 시각이면 400 응답에 쓸 메시지)
+    fn expiry(&self, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>, &'static str> {
+        let expires_at = match (self.expires_at, self.ttl_seconds) {
+            (Some(_), Some(_)) => return Err("expires_at 과 ttl_seconds 는 함께 쓸 수 없습니다"),
+            (Some(expires_at), None) => expires_at,
+            (None, Some(ttl)) => i64::try_from(ttl)
+                .ok()
+                .and_then(chrono::Duration::try_seconds)
+                .and_then(|ttl| now.checked_add_signed(ttl))
+                .ok_or("ttl_seconds 가 너무 큽니다")?,
+            (None, None) => return Ok(None),
+        };
+
+        if expires_at <= now {
+            return Err("만료 시각이 이미 지났습니다");
+        }
+        Ok(Some(expires_at))
+    }
+}
+
+// 메모 변경 이벤트 (GraphQL 구독, gRPC WatchMemos 에 전달, 삭제면 memo 가 없다)
+#[derive(Debug, Clone)]
+pub struct MemoChange {
+    pub action: AuditAction,
+    pub memo_id: u64,
+    pub memo: Option<Memo>,
+}
+
+// 애플리케이션 상태를 저장할 구조체
+// - next_id: 락 없이 원자적으로 증가하는 ID 할당기
+// - memos: 읽기는 동시에, 쓰기만 배타적으로 처리하는 RwLock 맵
+// - reminders: 알림 이벤트를 SSE 구독자에게 뿌리는 채널
+// - links: 메모별 본문에 적힌 위키 링크 대상 (생성/수정/삭제 때마다 갱신)
+//   링크는 조회할 때 해석하므로, 나중에 생긴 메모의 제목도 바로 연결된다
+// - notebooks: 노트북 트리 (next_notebook_id 로 ID 할당)
+// - templates: 이름 → 메모 템플릿
+// - audit: 메모 생성/수정/삭제 감사 로그
+// - changes: 메모 변경 이벤트 채널 (감사 로그를 남길 때 함께 보낸다)
+// - expiries: (만료 시각, 메모 ID) 색인 - 스위퍼가 맵 전체를 훑지 않고 지난 것만 꺼낸다
+// - cipher: 본문 암호화 키 (POST /admin/rotate-keys 로 실행 중에 바꿀 수 있다)
+// 락은 항상 notebooks → memos → links → expiries → audit → cipher 순서로 잡는다
+pub struct AppState {
+    memos: RwLock<HashMap<u64, Memo>>,
+    next_id: AtomicU64,
+    reminders: broadcast::Sender<Reminder>,
+    links: RwLock<HashMap<u64, Vec<String>>>,
+    expiries: RwLock<BTreeSet<(DateTime<Utc>, u64)>>,
+    notebooks: RwLock<HashMap<u64, Notebook>>,
+    next_notebook_id: AtomicU64,
+    templates: RwLock<HashMap<String, MemoTemplate>>,
+    audit: RwLock<AuditLog>,
+    changes: broadcast::Sender<MemoChange>,
+    // 설정되어 있으면 memos 에 저장되는 본문은 항상 암호문
+    cipher: RwLock<Option<ContentCipher>>,
+    // 설정되어 있을 때만 관리자 API 를 연다 (X-Admin-Token 헤더와 비교)
+    admin_token: Option<String>,
+}
+
+// 감사 로그는 메모리에만, 본문은 암호화하지 않는 기본 상태 (다른 앱에 마운트할 때)
+impl Default for AppState {
+    fn default() -> Self {
+        AppState::new(AuditLog::in_memory(), None)
+    }
+}
+
+impl AppState {
+    // 빈 상태 생성 (감사 로그와 본문 암호화 설정은 호출하는 쪽에서 정한다)
+    pub fn new(audit: AuditLog, cipher: Option<ContentCipher>) -> Self {
+        let (reminders, _) = broadcast::channel(100);
+        let (changes, _) = broadcast::channel(100);
+        AppState {
+            memos: RwLock::new(HashMap::new()),
+            next_id: AtomicU64::new(0),
+            reminders,
+            links: RwLock::new(HashMap::new()),
+            expiries: RwLock::new(BTreeSet::new()),
+            notebooks: RwLock::new(HashMap::new()),
+            next_notebook_id: AtomicU64::new(0),
+            templates: RwLock::new(HashMap::new()),
+            audit: RwLock::new(audit),
+            changes,
+            cipher: RwLock::new(cipher),
+            admin_token: None,
+        }
+    }
+
+    // 관리자 API 토큰 설정 (None 이면 관리자 API 를 닫는다)
+    pub fn with_admin_token(mut self, token: Option<String>) -> Self {
+        self.admin_token = token;
+        self
+    }
+
+    // 본문 암호화 키 교체 (이전 키로 저장된 본문은 run_key_rotation 이 다시 암호화한다)
+    // 저장된 본문을 새 키 목록으로 모두 열 수 있을 때만 바꾼다 - 이전 키를 빠뜨리면 본문을 잃는다
+    // 암호화하지 않던 서버에 켜지는 못한다 (시트 동기화는 시작할 때만 암호화 여부를 본다)
+    pub fn replace_cipher(&self, new_cipher: ContentCipher) -> Result<(), String> {
+        let memos = self.memos.read().unwrap();
+        let mut cipher = self.cipher.write().unwrap();
+        if cipher.is_none() {
+            return Err("본문 암호화가 설정되어 있지 않습니다 (MEMO_ENCRYPTION_KEY)".to_string());
+        }
+        if let Some(memo) = memos.values().find(|memo| new_cipher.open(&memo.content).is_err()) {
+            return Err(format!("메모 {} 를 새 키 목록으로 복호화할 수 없습니다 (이전 키를 old_keys 에 넣어야 합니다)", memo.id));
+        }
+
+        *cipher = Some(new_cipher);
+        Ok(())
+    }
+
+    // 저장할 본문 (암호화 설정이 없으면 평문 그대로)
+    fn seal_content(&self, content: &str) -> String {
+        match &*self.cipher.read().unwrap() {
+            Some(cipher) => cipher.seal(content),
+            None => content.to_string(),
+        }
+    }
+
+    // API 로 내보낼 메모 (본문 복호화)
+    // 복호화에 실패하면 빈 본문 대신 에러를 돌려주고 (API 는 500) 로그를 남긴다
+    fn reveal(&self, memo: &Memo) -> Result<Memo, String> {
+        let mut memo = memo.clone();
+        if let Some(cipher) = &*self.cipher.read().unwrap() {
+            memo.content = cipher.open(&memo.content).map_err(|e| {
+                eprintln!("메모 {} 복호화 실패: {}", memo.id, e);
+                format!("메모 {} 의 본문을 복호화할 수 없습니다", memo.id)
+            })?;
+        }
+        Ok(memo)
+    }
+
+    // 여러 메모를 복호화 (하나라도 실패하면 에러)
+    fn reveal_all<'a>(&self, memos: impl Iterator<Item = &'a Memo>) -> Result<Vec<Memo>, String> {
+        memos.map(|memo| self.reveal(memo)).collect()
+    }
+
+    // 만료 색인 갱신 (memos 쓰기 락을 잡은 채로 호출)
+    fn reindex_expiry(&self, memo_id: u64, old: Option<DateTime<Utc>>, new: Option<DateTime<Utc>>) {
+        if old == new {
+            return;
+        }
+        let mut expiries = self.expiries.write().unwrap();
+        if let Some(old) = old {
+            expiries.remove(&(old, memo_id));
+        }
+        if let Some(new) = new {
+            expiries.insert((new, memo_id));
+        }
+    }
+
+    // 감사 로그 기록 (다이제스트는 복호화한 메모 기준이라 키 교체로 바뀌지 않는다)
+    // 모든 변경이 여기를 지나므로 변경 이벤트도 여기서 보낸다
+    // 복호화하지 못한 메모는 저장된 그대로 남긴다 (변경 자체는 이미 일어났다)
+    fn record_audit(&self, action: AuditAction, actor: &Actor, memo_id: u64, before: Option<&Memo>, after: Option<&Memo>) {
+        let before = before.map(|memo| self.reveal(memo).unwrap_or_else(|_| memo.clone()));
+        let after = after.map(|memo| self.reveal(memo).unwrap_or_else(|_| memo.clone()));
+        self.audit.write().unwrap().record(action, actor, memo_id, before.as_ref(), after.as_ref());
+        // 구독자가 없으면 에러지만 무시해도 된다
+        self.changes.send(MemoChange { action, memo_id, memo: after }).ok();
+    }
+}
+
+// 메모를 만들어 저장 (create_memo, 템플릿 생성에서 공통 사용)
+// 실패하면 400 응답에 쓸 메시지를 돌려준다
+fn insert_memo(data: &AppState, actor: &Actor, memo_req: CreateMemoRequest) -> Result<Memo, &'static str> {
+    insert_memo_as(data, actor, memo_req, None)
+}
+
+// reserved: 미리 정해 둔 (ID, 생성 시각) - 시트 동기화는 시트에 ID 를 먼저 쓰고 나서 메모를 저장한다
+// 없으면 검증이 끝난 뒤에 새 ID 를 할당한다
+fn insert_memo_as(data: &AppState, actor: &Actor, memo_req: CreateMemoRequest, reserved: Option<(u64, DateTime<Utc>)>) -> Result<Memo, &'static str> {
+    // 노트북이 삭제되지 않도록 삽입이 끝날 때까지 읽기 락을 유지
+    let notebooks = data.notebooks.read().unwrap();
+    if let Some(notebook_id) = memo_req.notebook_id {
+        if !notebooks.contains_key(&notebook_id) {
+            return Err("노트북을 찾을 수 없습니다");
+        }
+    }
+
+    let now = reserved.map_or_else(Utc::now, |(_, created_at)| created_at);
+    let expires_at = memo_req.expiry(now)?;
+
+    // ID는 원자적으로 할당하므로 맵 락을 잡기 전에 메모를 만들 수 있다
+    let id = reserved.map_or_else(|| data.next_id.fetch_add(1, Ordering::Relaxed), |(id, _)| id);
+
+    let memo = Memo {
+        id,
+        title: memo_req.title,
+        content: memo_req.content,
+        created_at: now,
+        updated_at: now,
+        due_at: memo_req.due_at,
+        remind_at: memo_req.remind_at,
+        recurrence: memo_req.recurrence,
+        notebook_id: memo_req.notebook_id,
+        expires_at,
+    };
+
+    // 본문은 암호화해서 저장하고, 링크는 평문에서 뽑는다
+    let stored = Memo { content: data.seal_content(&memo.content), ..memo.clone() };
+
+    // 쓰기 락은 삽입하는 동안만 잡는다
+    let mut memos = data.memos.write().unwrap();
+    memos.insert(id, stored);
+    data.links.write().unwrap().insert(id, parse_wiki_links(&memo.content));
+    data.reindex_expiry(id, None, expires_at);
+    data.record_audit(AuditAction::Create, actor, id, None, Some(&memo));
+    drop(memos);
+    drop(notebooks);
+
+    Ok(memo)
+}
+
+// 메모 내용 수정 (update_memo, GraphQL, gRPC, 시트 동기화에서 공통 사용)
+// 요청에 노트북이나 만료 시각이 없으면 지금 값을 그대로 둔다
+// 메모가 없거나 만료됐으면 Ok(None), 요청이 잘못됐으면 400 응답에 쓸 메시지
+fn replace_memo(data: &AppState, actor: &Actor, id: u64, memo_req: CreateMemoRequest) -> Result<Option<Memo>, &'static str> {
+    // 노트북이 삭제되지 않도록 수정이 끝날 때까지 읽기 락을 유지
+    let notebooks = data.notebooks.read().unwrap();
+    if let Some(notebook_id) = memo_req.notebook_id {
+        if !notebooks.contains_key(&notebook_id) {
+            return Err("노트북을 찾을 수 없습니다");
+        }
+    }
+
+    let new_expiry = memo_req.expiry(Utc::now())?;
+
+    let mut memos = data.memos.write().unwrap();
+    let Some(memo) = memos.get_mut(&id).filter(|memo| !memo.is_expired()) else {
+        return Ok(None);
+    };
+    let expires_at = new_expiry.or(memo.expires_at);
+
+    let before = memo.clone();
+    memo.title = memo_req.title;
+    memo.content = data.seal_content(&memo_req.content);
+    memo.due_at = memo_req.due_at;
+    memo.remind_at = memo_req.remind_at;
+    memo.recurrence = memo_req.recurrence;
+    memo.notebook_id = memo_req.notebook_id.or(memo.notebook_id);
+    memo.expires_at = expires_at;
+    memo.updated_at = Utc::now();
+    // 본문이 바뀌었으니 링크 그래프도 갱신
+    data.links.write().unwrap().insert(id, parse_wiki_links(&memo_req.content));
+    data.reindex_expiry(id, before.expires_at, expires_at);
+    data.record_audit(AuditAction::Update, actor, id, Some(&before), Some(memo));
+
+    // 본문은 방금 받은 평문이므로 복호화할 필요가 없다
+    Ok(Some(Memo { content: memo_req.content, ..memo.clone() }))
+}
+
+// 메모 삭제 (delete_memo, GraphQL, gRPC 에서 공통 사용)
+// 메모가 없거나 만료됐으면 None (만료된 메모는 스위퍼가 지운다)
+// 지운 메모는 저장된 그대로 돌려준다 (본문이 필요하면 호출하는 쪽에서 reveal)
+fn remove_memo(data: &AppState, actor: &Actor, id: u64) -> Option<Memo> {
+    let mut memos = data.memos.write().unwrap();
+    if memos.get(&id).is_none_or(Memo::is_expired) {
+        return None;
+    }
+    let memo = memos.remove(&id)?;
+
+    // 이 메모를 가리키던 링크는 다음 조회부터 끊어진 링크가 된다
+    data.links.write().unwrap().remove(&id);
+    data.reindex_expiry(id, memo.expires_at, None);
+    data.record_audit(AuditAction::Delete, actor, id, Some(&memo), None);
+
+    Some(memo)
+}
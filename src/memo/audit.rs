@@ -0,0 +1,181 @@
+use super::Memo;
+use actix_web::HttpRequest;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+
+// 감사 로그에 남기는 변경 종류
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+// 감사 로그 항목 - hash 는 prev_hash 를 포함한 나머지 필드의 SHA-256 이라
+// 중간 항목을 고치거나 지우면 그 뒤로 체인이 끊어진다
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub action: AuditAction,
+    pub actor: String,
+    pub timestamp: DateTime<Utc>,
+    pub client_ip: Option<String>,
+    pub memo_id: u64,
+    // 변경 전/후 메모의 SHA-256 (생성이면 before, 삭제면 after 가 없다)
+    pub before_digest: Option<String>,
+    pub after_digest: Option<String>,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+// 감사 로그 조회 쿼리 (?memo_id=3&since=2024-01-01T00:00:00Z)
+#[derive(Debug, Deserialize)]
+pub struct AuditQuery {
+    pub memo_id: Option<u64>,
+    pub since: Option<DateTime<Utc>>,
+}
+
+// 변경을 일으킨 사용자 (X-User 헤더)와 접속 IP
+pub struct Actor {
+    pub name: String,
+    pub client_ip: Option<String>,
+}
+
+impl Actor {
+    pub fn from_request(req: &HttpRequest) -> Self {
+        Actor {
+            name: req.headers().get("X-User").and_then(|v| v.to_str().ok()).unwrap_or("anonymous").to_string(),
+            client_ip: req.peer_addr().map(|addr| addr.ip().to_string()),
+        }
+    }
+
+    // 서버 내부 작업 (알림 스케줄러 등)
+    pub fn system(name: &str) -> Self {
+        Actor { name: name.to_string(), client_ip: None }
+    }
+}
+
+// 해시 체인 감사 로그
+// 파일 로그는 JSON Lines 파일에 덧붙이기만 하고 메모리에는 체인 끝(다음 순번, 마지막 해시)만 둔다
+// 재시작하면 파일을 읽어 체인을 이어가고, 검증은 파일로 한다 (main verify-audit)
+pub struct AuditLog {
+    storage: AuditStorage,
+    next_seq: u64,
+    last_hash: String,
+}
+
+enum AuditStorage {
+    // 테스트 등 파일 없이 쓸 때
+    Memory(Vec<AuditEntry>),
+    File { path: String, file: File },
+}
+
+// 첫 항목의 prev_hash
+const AUDIT_GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+// 메모 내용의 SHA-256 (감사 로그의 before/after)
+fn memo_digest(memo: &Memo) -> String {
+    format!("{:x}", Sha256::digest(serde_json::to_vec(memo).unwrap()))
+}
+
+// hash 필드를 비운 항목의 SHA-256
+fn audit_entry_hash(entry: &AuditEntry) -> String {
+    let unsigned = AuditEntry { hash: String::new(), ..entry.clone() };
+    format!("{:x}", Sha256::digest(serde_json::to_vec(&unsigned).unwrap()))
+}
+
+// 체인 검증 - 순번, prev_hash 연결, 각 항목의 해시를 확인하고 처음 깨진 위치를 알려준다
+pub fn verify_audit_chain(entries: &[AuditEntry]) -> Result<(), String> {
+    let mut prev_hash = AUDIT_GENESIS_HASH;
+
+    for (i, entry) in entries.iter().enumerate() {
+        if entry.seq != i as u64 {
+            return Err(format!("{}번째 항목의 순번이 {} 입니다 (누락 또는 순서 변경)", i, entry.seq));
+        }
+        if entry.prev_hash != prev_hash {
+            return Err(format!("항목 {} 의 prev_hash 가 이전 항목과 이어지지 않습니다", entry.seq));
+        }
+        if audit_entry_hash(entry) != entry.hash {
+            return Err(format!("항목 {} 의 내용이 해시와 일치하지 않습니다 (변조)", entry.seq));
+        }
+        prev_hash = &entry.hash;
+    }
+
+    Ok(())
+}
+
+// 감사 로그 파일 읽기 (JSON Lines)
+pub fn read_audit_file(path: &str) -> Result<Vec<AuditEntry>, Box<dyn std::error::Error>> {
+    let mut entries = Vec::new();
+    for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(serde_json::from_str(&line).map_err(|e| format!("{}번째 줄을 읽을 수 없습니다: {}", i + 1, e))?);
+    }
+    Ok(entries)
+}
+
+impl AuditLog {
+    // 파일 없이 메모리에만 보관하는 로그
+    pub fn in_memory() -> Self {
+        AuditLog { storage: AuditStorage::Memory(Vec::new()), next_seq: 0, last_hash: AUDIT_GENESIS_HASH.to_string() }
+    }
+
+    // 감사 로그 파일을 열고 (없으면 만든다) 기존 항목 뒤로 체인을 이어간다
+    pub fn open(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let entries = if std::path::Path::new(path).exists() { read_audit_file(path)? } else { Vec::new() };
+        if let Err(e) = verify_audit_chain(&entries) {
+            eprintln!("경고: 기존 감사 로그 체인이 깨져 있습니다: {}", e);
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(AuditLog {
+            storage: AuditStorage::File { path: path.to_string(), file },
+            next_seq: entries.len() as u64,
+            last_hash: entries.last().map_or(AUDIT_GENESIS_HASH.to_string(), |last| last.hash.clone()),
+        })
+    }
+
+    // 지금까지의 항목 (파일 로그면 파일에서 읽는다)
+    pub fn entries(&self) -> Result<Vec<AuditEntry>, String> {
+        match &self.storage {
+            AuditStorage::Memory(entries) => Ok(entries.clone()),
+            AuditStorage::File { path, .. } => read_audit_file(path).map_err(|e| format!("감사 로그를 읽을 수 없습니다: {}", e)),
+        }
+    }
+
+    // 변경 하나를 체인 끝에 추가
+    pub fn record(&mut self, action: AuditAction, actor: &Actor, memo_id: u64, before: Option<&Memo>, after: Option<&Memo>) {
+        let mut entry = AuditEntry {
+            seq: self.next_seq,
+            action,
+            actor: actor.name.clone(),
+            timestamp: Utc::now(),
+            client_ip: actor.client_ip.clone(),
+            memo_id,
+            before_digest: before.map(memo_digest),
+            after_digest: after.map(memo_digest),
+            prev_hash: self.last_hash.clone(),
+            hash: String::new(),
+        };
+        entry.hash = audit_entry_hash(&entry);
+
+        // 기록에 실패해도 체인은 이어간다 (빠진 항목은 검증할 때 끊어진 체인으로 드러난다)
+        self.next_seq += 1;
+        self.last_hash = entry.hash.clone();
+        match &mut self.storage {
+            AuditStorage::Memory(entries) => entries.push(entry),
+            AuditStorage::File { file, .. } => {
+                if let Err(e) = writeln!(file, "{}", serde_json::to_string(&entry).unwrap()) {
+                    eprintln!("감사 로그 파일 기록 실패: {}", e);
+                }
+            }
+        }
+    }
+}
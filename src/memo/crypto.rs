@@ -0,0 +1,146 @@
+use super::AppState;
+use actix_web::web;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use std::collections::HashMap;
+
+// 메모 본문 암호화 (AES-256-GCM)
+// 저장되는 본문은 "enc:v1:<키 ID>:<base64(nonce + 암호문)>" 형태이고 API 응답에서는 복호화된다
+// - MEMO_ENCRYPTION_KEY: 새로 암호화할 때 쓰는 키 "<키 ID>:<base64 32바이트>"
+// - MEMO_ENCRYPTION_OLD_KEYS: 복호화만 하는 이전 키 목록 (쉼표 구분, 키 교체용)
+pub struct ContentCipher {
+    active_key_id: String,
+    keys: HashMap<String, Aes256Gcm>,
+}
+
+// 암호화된 본문 앞에 붙는 표시
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+impl ContentCipher {
+    // "<키 ID>:<base64 키>" 하나를 읽는다
+    fn parse_key(spec: &str) -> Result<(String, Aes256Gcm), String> {
+        let (id, key) = spec.trim().split_once(':').ok_or_else(|| format!("키 형식은 <ID>:<base64> 입니다: {}", spec))?;
+        let key = BASE64.decode(key).map_err(|e| format!("키 {} 를 base64 로 읽을 수 없습니다: {}", id, e))?;
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| format!("키 {} 는 32바이트여야 합니다", id))?;
+        Ok((id.to_string(), cipher))
+    }
+
+    // 현재 키와 복호화만 하는 이전 키로 만든다 (모두 "<키 ID>:<base64 키>")
+    pub fn new(active: &str, old_keys: &[&str]) -> Result<Self, String> {
+        let (active_key_id, active_cipher) = Self::parse_key(active)?;
+        let mut keys = HashMap::from([(active_key_id.clone(), active_cipher)]);
+
+        for spec in old_keys {
+            let (id, cipher) = Self::parse_key(spec)?;
+            keys.entry(id).or_insert(cipher);
+        }
+
+        Ok(ContentCipher { active_key_id, keys })
+    }
+
+    // 환경변수에서 키를 읽는다 (MEMO_ENCRYPTION_KEY 가 없으면 암호화하지 않음)
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Ok(active) = std::env::var("MEMO_ENCRYPTION_KEY") else {
+            return Ok(None);
+        };
+
+        let old_keys = std::env::var("MEMO_ENCRYPTION_OLD_KEYS").unwrap_or_default();
+        let old_keys: Vec<&str> = old_keys.split(',').filter(|spec| !spec.trim().is_empty()).collect();
+        Self::new(&active, &old_keys).map(Some)
+    }
+
+    // 새로 암호화할 때 쓰는 키 ID
+    pub fn key_id(&self) -> &str {
+        &self.active_key_id
+    }
+
+    // 현재 키로 암호화
+    pub fn seal(&self, plaintext: &str) -> String {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self.keys[&self.active_key_id].encrypt(&nonce, plaintext.as_bytes()).expect("AES-GCM 암호화 실패");
+
+        let mut payload = nonce.to_vec();
+        payload.extend(ciphertext);
+        format!("{}{}:{}", ENCRYPTED_PREFIX, self.active_key_id, BASE64.encode(payload))
+    }
+
+    // 암호화된 본문이면 복호화, 아니면(암호화 전에 저장된 본문) 그대로
+    pub fn open(&self, stored: &str) -> Result<String, String> {
+        let Some(sealed) = stored.strip_prefix(ENCRYPTED_PREFIX) else {
+            return Ok(stored.to_string());
+        };
+
+        let (key_id, payload) = sealed.split_once(':').ok_or("암호문 형식이 올바르지 않습니다")?;
+        let cipher = self.keys.get(key_id).ok_or_else(|| format!("키 {} 가 없습니다", key_id))?;
+        let payload = BASE64.decode(payload).map_err(|e| e.to_string())?;
+        if payload.len() < 12 {
+            return Err("암호문이 너무 짧습니다".to_string());
+        }
+
+        let (nonce, ciphertext) = payload.split_at(12);
+        let plaintext = cipher.decrypt(Nonce::from_slice(nonce), ciphertext).map_err(|_| format!("키 {} 로 복호화할 수 없습니다", key_id))?;
+        String::from_utf8(plaintext).map_err(|e| e.to_string())
+    }
+
+    // 현재 키로 암호화되어 있지 않으면(이전 키 또는 평문) 다시 암호화해야 한다
+    pub fn needs_rotation(&self, stored: &str) -> bool {
+        !stored.starts_with(&format!("{}{}:", ENCRYPTED_PREFIX, self.active_key_id))
+    }
+}
+
+// ids 의 본문을 현재 키로 다시 암호화하고 처리한 개수를 돌려준다
+fn rotate_batch(data: &AppState, ids: &[u64]) -> usize {
+    let mut memos = data.memos.write().unwrap();
+    // 배치 사이에 키가 또 바뀌었을 수 있으므로 매번 현재 키를 본다
+    let cipher = data.cipher.read().unwrap();
+    let Some(cipher) = cipher.as_ref() else { return 0 };
+    let mut rotated = 0;
+
+    for id in ids {
+        // 락을 놓은 사이 지워졌거나 이미 새 키로 저장됐을 수 있다
+        let Some(memo) = memos.get_mut(id) else { continue };
+        if !cipher.needs_rotation(&memo.content) {
+            continue;
+        }
+
+        match cipher.open(&memo.content) {
+            Ok(plaintext) => {
+                memo.content = cipher.seal(&plaintext);
+                rotated += 1;
+            }
+            Err(e) => eprintln!("메모 {} 재암호화 실패: {}", id, e),
+        }
+    }
+
+    rotated
+}
+
+// 키 교체 - 이전 키(또는 평문)로 저장된 본문을 현재 키로 다시 암호화하고 처리한 개수를 돌려준다
+// 키가 바뀐 뒤에 저장하는 본문은 항상 현재 키를 쓰므로 키가 바뀔 때마다 한 번 훑으면 된다
+// (서버를 시작할 때, POST /admin/rotate-keys 로 키를 바꿨을 때)
+// 쓰기 락을 오래 잡지 않도록 조금씩 나눠서 처리한다
+pub async fn run_key_rotation(data: web::Data<AppState>) -> usize {
+    const BATCH_SIZE: usize = 100;
+
+    let stale: Vec<u64> = {
+        let memos = data.memos.read().unwrap();
+        let cipher = data.cipher.read().unwrap();
+        let Some(cipher) = cipher.as_ref() else { return 0 };
+        memos.values().filter(|memo| cipher.needs_rotation(&memo.content)).map(|memo| memo.id).collect()
+    };
+    let mut rotated = 0;
+
+    for batch in stale.chunks(BATCH_SIZE) {
+        rotated += rotate_batch(&data, batch);
+        tokio::task::yield_now().await;
+    }
+
+    if rotated > 0 {
+        if let Some(cipher) = &*data.cipher.read().unwrap() {
+            println!("메모 {}개를 키 {} 로 재암호화했습니다", rotated, cipher.active_key_id);
+        }
+    }
+    rotated
+}
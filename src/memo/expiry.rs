@@ -0,0 +1,51 @@
+use super::audit::{Actor, AuditAction};
+use super::AppState;
+use actix_web::web;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+// now 까지 만료된 메모를 지우고 지운 개수를 돌려준다 (스위퍼는 현재 시각, 테스트는 정한 시각으로 부른다)
+// 만료 색인에서 now 이전 항목만 꺼내므로 메모 맵 전체를 훑지 않는다
+pub fn sweep_expired(data: &AppState, now: DateTime<Utc>) -> usize {
+    // 지울 것이 없으면 쓰기 락을 잡지 않는다
+    if data.expiries.read().unwrap().first().is_none_or(|(expires_at, _)| *expires_at > now) {
+        return 0;
+    }
+
+    let mut memos = data.memos.write().unwrap();
+    let mut links = data.links.write().unwrap();
+    let mut expiries = data.expiries.write().unwrap();
+    let actor = Actor::system("expiry");
+    let mut removed = 0;
+
+    while let Some(&(expires_at, memo_id)) = expiries.first() {
+        if expires_at > now {
+            break;
+        }
+        expiries.pop_first();
+
+        let Some(memo) = memos.remove(&memo_id) else { continue };
+        links.remove(&memo_id);
+        data.record_audit(AuditAction::Delete, &actor, memo_id, Some(&memo), None);
+        removed += 1;
+    }
+
+    removed
+}
+
+// 백그라운드 만료 스위퍼
+// - MEMO_EXPIRY_SWEEP_SECONDS: 스위프 간격 (기본 30)
+// 만료된 메모는 스위프 전에도 조회에서 빠지므로 간격은 메모리 회수 주기일 뿐이다
+pub async fn run_expiry_sweeper(data: web::Data<AppState>) {
+    let seconds = std::env::var("MEMO_EXPIRY_SWEEP_SECONDS").ok().and_then(|s| s.parse().ok()).unwrap_or(30);
+    let mut interval = tokio::time::interval(Duration::from_secs(seconds));
+
+    loop {
+        interval.tick().await;
+
+        let removed = sweep_expired(&data, Utc::now());
+        if removed > 0 {
+            println!("만료된 메모 {}개를 지웠습니다", removed);
+        }
+    }
+}
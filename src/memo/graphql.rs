@@ -0,0 +1,246 @@
+use super::audit::{Actor, AuditAction};
+use super::{insert_memo, remove_memo, replace_memo, AppState, CreateMemoRequest, Memo, MemoChange, Recurrence};
+use actix_web::{web, HttpRequest, HttpResponse};
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{Context, Enum, Error, InputObject, Object, Result, Schema, SimpleObject, Subscription};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse, GraphQLSubscription};
+use chrono::{DateTime, Utc};
+use futures_util::Stream;
+use tokio::sync::broadcast;
+
+// GraphQL 스키마 - REST 핸들러와 같은 AppState 를 요청마다 넘겨받는다
+pub type MemoSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
+
+// 한 번에 돌려주는 메모 수 상한
+const MAX_PAGE_SIZE: usize = 100;
+
+pub fn schema() -> MemoSchema {
+    Schema::build(QueryRoot, MutationRoot, SubscriptionRoot).limit_depth(10).finish()
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+#[graphql(remote = "Recurrence")]
+enum RecurrenceKind {
+    Daily,
+    Weekly,
+}
+
+// 변경 종류 (감사 로그와 같다)
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+#[graphql(remote = "AuditAction")]
+enum ChangeKind {
+    Create,
+    Update,
+    Delete,
+}
+
+#[Object]
+impl Memo {
+    async fn id(&self) -> u64 {
+        self.id
+    }
+
+    async fn title(&self) -> &str {
+        &self.title
+    }
+
+    async fn content(&self) -> &str {
+        &self.content
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    async fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    async fn due_at(&self) -> Option<DateTime<Utc>> {
+        self.due_at
+    }
+
+    async fn remind_at(&self) -> Option<DateTime<Utc>> {
+        self.remind_at
+    }
+
+    async fn recurrence(&self) -> Option<RecurrenceKind> {
+        self.recurrence.map(Into::into)
+    }
+
+    async fn notebook_id(&self) -> Option<u64> {
+        self.notebook_id
+    }
+
+    async fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+}
+
+#[Object]
+impl MemoChange {
+    async fn kind(&self) -> ChangeKind {
+        self.action.into()
+    }
+
+    async fn memo_id(&self) -> u64 {
+        self.memo_id
+    }
+
+    // 삭제 이벤트면 null
+    async fn memo(&self) -> Option<&Memo> {
+        self.memo.as_ref()
+    }
+}
+
+// 메모 생성/수정 입력 (REST 의 CreateMemoRequest 와 같은 필드)
+#[derive(InputObject)]
+struct MemoInput {
+    title: String,
+    content: String,
+    due_at: Option<DateTime<Utc>>,
+    remind_at: Option<DateTime<Utc>>,
+    recurrence: Option<RecurrenceKind>,
+    // 수정할 때 없으면 지금 노트북 유지
+    notebook_id: Option<u64>,
+    // 둘 중 하나만 (수정할 때 둘 다 없으면 지금 만료 시각 유지)
+    expires_at: Option<DateTime<Utc>>,
+    ttl_seconds: Option<u64>,
+}
+
+impl From<MemoInput> for CreateMemoRequest {
+    fn from(input: MemoInput) -> Self {
+        CreateMemoRequest {
+            title: input.title,
+            content: input.content,
+            due_at: input.due_at,
+            remind_at: input.remind_at,
+            recurrence: input.recurrence.map(Into::into),
+            notebook_id: input.notebook_id,
+            expires_at: input.expires_at,
+            ttl_seconds: input.ttl_seconds,
+        }
+    }
+}
+
+// 메모 목록 필터 (모두 AND 로 적용)
+#[derive(InputObject, Default)]
+struct MemoFilter {
+    // 제목이나 본문에 들어간 검색어 (대소문자 무시)
+    q: Option<String>,
+    notebook_id: Option<u64>,
+    due_before: Option<DateTime<Utc>>,
+    created_after: Option<DateTime<Utc>>,
+}
+
+impl MemoFilter {
+    fn matches(&self, memo: &Memo) -> bool {
+        let q = self.q.as_deref().map(str::to_lowercase);
+        q.is_none_or(|q| memo.title.to_lowercase().contains(&q) || memo.content.to_lowercase().contains(&q))
+            && self.notebook_id.is_none_or(|notebook_id| memo.notebook_id == Some(notebook_id))
+            && self.due_before.is_none_or(|before| memo.due_at.is_some_and(|due_at| due_at <= before))
+            && self.created_after.is_none_or(|after| memo.created_at >= after)
+    }
+}
+
+// 메모 한 페이지 (offset/limit)
+#[derive(SimpleObject)]
+struct MemoPage {
+    items: Vec<Memo>,
+    total_count: usize,
+    has_next_page: bool,
+}
+
+fn state<'a>(ctx: &Context<'a>) -> &'a web::Data<AppState> {
+    ctx.data_unchecked::<web::Data<AppState>>()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn memo(&self, ctx: &Context<'_>, id: u64) -> Result<Option<Memo>> {
+        let data = state(ctx);
+        data.memos.read().unwrap().get(&id).filter(|memo| !memo.is_expired()).map(|memo| data.reveal(memo)).transpose().map_err(Error::new)
+    }
+
+    // ID 순으로 정렬한 뒤 필터를 적용하고 offset 부터 limit 개
+    async fn memos(&self, ctx: &Context<'_>, filter: Option<MemoFilter>, #[graphql(default = 0)] offset: usize, #[graphql(default = 20)] limit: usize) -> Result<MemoPage> {
+        if limit > MAX_PAGE_SIZE {
+            return Err(Error::new(format!("limit 은 {} 이하여야 합니다", MAX_PAGE_SIZE)));
+        }
+
+        let data = state(ctx);
+        let filter = filter.unwrap_or_default();
+        // 본문이 암호화돼 있을 수 있으므로 복호화한 뒤에 거른다
+        let mut memos = data.reveal_all(data.memos.read().unwrap().values().filter(|memo| !memo.is_expired())).map_err(Error::new)?;
+        memos.retain(|memo| filter.matches(memo));
+        memos.sort_by_key(|memo| memo.id);
+
+        let total_count = memos.len();
+        let items: Vec<Memo> = memos.into_iter().skip(offset).take(limit).collect();
+        let has_next_page = offset + items.len() < total_count;
+
+        Ok(MemoPage { items, total_count, has_next_page })
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn create_memo(&self, ctx: &Context<'_>, input: MemoInput) -> Result<Memo> {
+        insert_memo(state(ctx), ctx.data_unchecked::<Actor>(), input.into()).map_err(Error::new)
+    }
+
+    async fn update_memo(&self, ctx: &Context<'_>, id: u64, input: MemoInput) -> Result<Memo> {
+        replace_memo(state(ctx), ctx.data_unchecked::<Actor>(), id, input.into()).map_err(Error::new)?.ok_or_else(|| Error::new("메모를 찾을 수 없습니다"))
+    }
+
+    // 삭제한 메모를 돌려준다
+    async fn delete_memo(&self, ctx: &Context<'_>, id: u64) -> Result<Memo> {
+        let data = state(ctx);
+        let memo = remove_memo(data, ctx.data_unchecked::<Actor>(), id).ok_or_else(|| Error::new("메모를 찾을 수 없습니다"))?;
+        data.reveal(&memo).map_err(Error::new)
+    }
+}
+
+pub struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    // 메모 생성/수정/삭제 이벤트 (memo_id 를 주면 그 메모만)
+    async fn memo_changes(&self, ctx: &Context<'_>, memo_id: Option<u64>) -> impl Stream<Item = MemoChange> {
+        let rx = state(ctx).changes.subscribe();
+
+        futures_util::stream::unfold(rx, move |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(change) if memo_id.is_none_or(|memo_id| change.memo_id == memo_id) => return Some((change, rx)),
+                    Ok(_) => continue,
+                    // 구독자가 느려서 놓친 이벤트는 건너뛴다
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
+
+// POST /graphql - 쿼리와 뮤테이션
+pub async fn graphql(req: HttpRequest, schema: web::Data<MemoSchema>, data: web::Data<AppState>, gql_req: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(gql_req.into_inner().data(data).data(Actor::from_request(&req))).await.into()
+}
+
+// GET /graphql (WebSocket) - 구독
+pub async fn graphql_ws(req: HttpRequest, schema: web::Data<MemoSchema>, data: web::Data<AppState>, payload: web::Payload) -> actix_web::Result<HttpResponse> {
+    let mut extra = async_graphql::Data::default();
+    extra.insert(data);
+    extra.insert(Actor::from_request(&req));
+    GraphQLSubscription::new(MemoSchema::clone(&schema)).with_data(extra).start(&req, payload)
+}
+
+// GET /graphql (브라우저) - GraphiQL 화면 (경로 접두사가 붙어도 동작하도록 현재 경로 사용)
+pub async fn graphiql(req: HttpRequest) -> HttpResponse {
+    HttpResponse::Ok().content_type("text/html; charset=utf-8").body(GraphiQLSource::build().endpoint(req.path()).subscription_endpoint(req.path()).finish())
+}
@@ -0,0 +1,180 @@
+use super::audit::{Actor, AuditAction};
+use super::{insert_memo, remove_memo, replace_memo, AppState, CreateMemoRequest, Memo, Recurrence};
+use actix_web::web;
+use chrono::{DateTime, Utc};
+use futures_util::Stream;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use tokio::sync::broadcast;
+use tonic::{Request, Response, Status};
+
+// build.rs 가 proto/memo.proto 에서 생성한 코드
+pub mod pb {
+    tonic::include_proto!("memo.v1");
+}
+
+use pb::memo_service_server::{MemoService, MemoServiceServer};
+
+// 한 번에 돌려주는 메모 수 상한 (limit 이 0 이면 이 값)
+const MAX_PAGE_SIZE: usize = 100;
+
+fn to_timestamp(time: DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp { seconds: time.timestamp(), nanos: time.timestamp_subsec_nanos() as i32 }
+}
+
+// 잘못된 시각이면 INVALID_ARGUMENT 에 쓸 메시지 (Status 는 커서 호출하는 쪽에서 만든다)
+fn from_timestamp(timestamp: Option<prost_types::Timestamp>) -> Result<Option<DateTime<Utc>>, &'static str> {
+    timestamp.map(|ts| u32::try_from(ts.nanos).ok().and_then(|nanos| DateTime::from_timestamp(ts.seconds, nanos)).ok_or("시각이 올바르지 않습니다")).transpose()
+}
+
+fn recurrence_from_pb(recurrence: pb::Recurrence) -> Option<Recurrence> {
+    match recurrence {
+        pb::Recurrence::Unspecified => None,
+        pb::Recurrence::Daily => Some(Recurrence::Daily),
+        pb::Recurrence::Weekly => Some(Recurrence::Weekly),
+    }
+}
+
+impl From<Memo> for pb::Memo {
+    fn from(memo: Memo) -> Self {
+        let recurrence = match memo.recurrence {
+            None => pb::Recurrence::Unspecified,
+            Some(Recurrence::Daily) => pb::Recurrence::Daily,
+            Some(Recurrence::Weekly) => pb::Recurrence::Weekly,
+        };
+
+        pb::Memo {
+            id: memo.id,
+            title: memo.title,
+            content: memo.content,
+            created_at: Some(to_timestamp(memo.created_at)),
+            due_at: memo.due_at.map(to_timestamp),
+            remind_at: memo.remind_at.map(to_timestamp),
+            recurrence: recurrence.into(),
+            notebook_id: memo.notebook_id,
+            updated_at: Some(to_timestamp(memo.updated_at)),
+            expires_at: memo.expires_at.map(to_timestamp),
+        }
+    }
+}
+
+impl From<AuditAction> for pb::ChangeKind {
+    fn from(action: AuditAction) -> Self {
+        match action {
+            AuditAction::Create => pb::ChangeKind::Create,
+            AuditAction::Update => pb::ChangeKind::Update,
+            AuditAction::Delete => pb::ChangeKind::Delete,
+        }
+    }
+}
+
+// 변경한 사용자 (x-user 메타데이터)와 접속 IP
+fn actor<T>(request: &Request<T>) -> Actor {
+    Actor {
+        name: request.metadata().get("x-user").and_then(|v| v.to_str().ok()).unwrap_or("anonymous").to_string(),
+        client_ip: request.remote_addr().map(|addr| addr.ip().to_string()),
+    }
+}
+
+// REST 핸들러와 같은 AppState 를 공유하는 gRPC 서비스 (grpc_service 로 만든다)
+pub struct MemoGrpc {
+    data: web::Data<AppState>,
+}
+
+#[tonic::async_trait]
+impl MemoService for MemoGrpc {
+    async fn create_memo(&self, request: Request<pb::CreateMemoRequest>) -> Result<Response<pb::Memo>, Status> {
+        let actor = actor(&request);
+        let req = request.into_inner();
+        let recurrence = recurrence_from_pb(req.recurrence());
+
+        let memo_req = CreateMemoRequest { title: req.title, content: req.content, due_at: from_timestamp(req.due_at).map_err(Status::invalid_argument)?, remind_at: from_timestamp(req.remind_at).map_err(Status::invalid_argument)?, recurrence, notebook_id: req.notebook_id, expires_at: from_timestamp(req.expires_at).map_err(Status::invalid_argument)?, ttl_seconds: req.ttl_seconds };
+        let memo = insert_memo(&self.data, &actor, memo_req).map_err(Status::invalid_argument)?;
+        Ok(Response::new(memo.into()))
+    }
+
+    async fn get_memo(&self, request: Request<pb::GetMemoRequest>) -> Result<Response<pb::Memo>, Status> {
+        let memos = self.data.memos.read().unwrap();
+        match memos.get(&request.get_ref().id).filter(|memo| !memo.is_expired()) {
+            Some(memo) => Ok(Response::new(self.data.reveal(memo).map_err(Status::internal)?.into())),
+            None => Err(Status::not_found("메모를 찾을 수 없습니다")),
+        }
+    }
+
+    async fn list_memos(&self, request: Request<pb::ListMemosRequest>) -> Result<Response<pb::ListMemosResponse>, Status> {
+        let req = request.into_inner();
+        let limit = match req.limit as usize {
+            0 => MAX_PAGE_SIZE,
+            limit => limit.min(MAX_PAGE_SIZE),
+        };
+
+        let mut memos = self.data.reveal_all(self.data.memos.read().unwrap().values().filter(|memo| !memo.is_expired())).map_err(Status::internal)?;
+        memos.sort_by_key(|memo| memo.id);
+
+        // 본문이 암호화돼 있을 수 있으므로 복호화한 뒤에 검색한다
+        if !req.q.is_empty() {
+            let q = req.q.to_lowercase();
+            memos.retain(|memo| memo.title.to_lowercase().contains(&q) || memo.content.to_lowercase().contains(&q));
+        }
+
+        let total_count = memos.len() as u32;
+        let memos = memos.into_iter().skip(req.offset as usize).take(limit).map(pb::Memo::from).collect();
+        Ok(Response::new(pb::ListMemosResponse { memos, total_count }))
+    }
+
+    async fn update_memo(&self, request: Request<pb::UpdateMemoRequest>) -> Result<Response<pb::Memo>, Status> {
+        let actor = actor(&request);
+        let req = request.into_inner();
+        let recurrence = recurrence_from_pb(req.recurrence());
+
+        let memo_req = CreateMemoRequest { title: req.title, content: req.content, due_at: from_timestamp(req.due_at).map_err(Status::invalid_argument)?, remind_at: from_timestamp(req.remind_at).map_err(Status::invalid_argument)?, recurrence, notebook_id: None, expires_at: from_timestamp(req.expires_at).map_err(Status::invalid_argument)?, ttl_seconds: req.ttl_seconds };
+        match replace_memo(&self.data, &actor, req.id, memo_req).map_err(Status::invalid_argument)? {
+            Some(memo) => Ok(Response::new(memo.into())),
+            None => Err(Status::not_found("메모를 찾을 수 없습니다")),
+        }
+    }
+
+    async fn delete_memo(&self, request: Request<pb::DeleteMemoRequest>) -> Result<Response<pb::Memo>, Status> {
+        let actor = actor(&request);
+        match remove_memo(&self.data, &actor, request.get_ref().id) {
+            Some(memo) => Ok(Response::new(self.data.reveal(&memo).map_err(Status::internal)?.into())),
+            None => Err(Status::not_found("메모를 찾을 수 없습니다")),
+        }
+    }
+
+    type WatchMemosStream = Pin<Box<dyn Stream<Item = Result<pb::MemoEvent, Status>> + Send>>;
+
+    async fn watch_memos(&self, request: Request<pb::WatchMemosRequest>) -> Result<Response<Self::WatchMemosStream>, Status> {
+        let memo_id = request.get_ref().memo_id;
+        let rx = self.data.changes.subscribe();
+
+        let stream = futures_util::stream::unfold(rx, move |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(change) if memo_id.is_none_or(|memo_id| change.memo_id == memo_id) => {
+                        let event = pb::MemoEvent { kind: pb::ChangeKind::from(change.action).into(), memo_id: change.memo_id, memo: change.memo.map(pb::Memo::from) };
+                        return Some((Ok(event), rx));
+                    }
+                    Ok(_) => continue,
+                    // 구독자가 느려서 놓친 이벤트는 건너뛴다
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+// 메모 gRPC 서비스 (다른 tonic 서버에 붙이거나 테스트에서 직접 띄울 때)
+pub fn grpc_service(data: web::Data<AppState>) -> MemoServiceServer<MemoGrpc> {
+    MemoServiceServer::new(MemoGrpc { data })
+}
+
+// gRPC 서버 실행 (actix HTTP 서버와 같은 AppState 사용)
+pub async fn serve_grpc(data: web::Data<AppState>, addr: SocketAddr) {
+    if let Err(e) = tonic::transport::Server::builder().add_service(grpc_service(data)).serve(addr).await {
+        eprintln!("gRPC 서버 오류: {}", e);
+    }
+}
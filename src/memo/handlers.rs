@@ -0,0 +1,548 @@
+use super::audit::{Actor, AuditAction, AuditEntry, AuditQuery};
+use super::links::{resolve_link, title_index, LinkSource, MemoLink, MemoLinks};
+use super::markdown::{extract_tasks, render_markdown};
+use super::notebook::{notebook_subtree, ContentsQuery, DeleteMode, DeleteNotebookQuery, MoveMemoRequest, Notebook, NotebookContents, NotebookRequest};
+use super::template::{fill_template, template_variables, FromTemplateRequest, MemoTemplate, MissingVariables, TemplateRequest};
+use super::crypto::{run_key_rotation, ContentCipher};
+use super::{graphql, insert_memo, remove_memo, replace_memo, AppState, CreateMemoRequest, Memo};
+use actix_web::web::Bytes;
+use actix_web::{guard, web, HttpRequest, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use tokio::sync::broadcast;
+
+// 목록 조회 쿼리
+// - ?render=html 이면 content_html 을 함께 내려준다
+// - ?q=검색어 면 제목이나 본문에 검색어가 들어간 메모만 (대소문자 무시)
+#[derive(Debug, Deserialize)]
+struct ListQuery {
+    render: Option<String>,
+    q: Option<String>,
+}
+
+// 마감 메모 조회 쿼리 (?before=2024-01-01T00:00:00Z, 없으면 현재 시각)
+#[derive(Debug, Deserialize)]
+struct DueQuery {
+    before: Option<DateTime<Utc>>,
+    render: Option<String>,
+}
+
+// 체크리스트 조회 쿼리 (?checked=false 면 열린 항목만)
+#[derive(Debug, Deserialize)]
+struct TaskQuery {
+    checked: Option<bool>,
+}
+
+// HTML 로 렌더링된 메모 (메모 필드 + content_html)
+#[derive(Debug, Serialize)]
+struct RenderedMemo<'a> {
+    #[serde(flatten)]
+    memo: &'a Memo,
+    content_html: String,
+}
+
+// 메모 본문에서 뽑아낸 체크리스트 항목
+#[derive(Debug, Serialize)]
+struct TaskItem {
+    memo_id: u64,
+    memo_title: String,
+    text: String,
+    checked: bool,
+}
+
+// render=html 여부에 따라 메모 목록을 그대로 또는 HTML 과 함께 응답
+fn memo_list_response(memos: Vec<&Memo>, render: Option<&str>) -> HttpResponse {
+    match render {
+        Some("html") => HttpResponse::Ok().json(memos.into_iter().map(|memo| RenderedMemo { memo, content_html: render_markdown(&memo.content) }).collect::<Vec<_>>()),
+        _ => HttpResponse::Ok().json(memos),
+    }
+}
+
+// CREATE - 새 메모 생성
+async fn create_memo(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    memo_req: web::Json<CreateMemoRequest>,
+) -> impl Responder {
+    match insert_memo(&data, &Actor::from_request(&req), memo_req.into_inner()) {
+        Ok(memo) => HttpResponse::Ok().json(memo),
+        Err(message) => HttpResponse::BadRequest().body(message),
+    }
+}
+
+// CREATE - 템플릿 저장 (같은 이름이 있으면 덮어쓴다)
+async fn create_template(data: web::Data<AppState>, template_req: web::Json<TemplateRequest>) -> impl Responder {
+    let template_req = template_req.into_inner();
+    if template_req.name.trim().is_empty() {
+        return HttpResponse::BadRequest().body("템플릿 이름이 필요합니다");
+    }
+
+    let variables = template_variables(&[&template_req.title, &template_req.content]);
+    let template = MemoTemplate { name: template_req.name, title: template_req.title, content: template_req.content, variables };
+    data.templates.write().unwrap().insert(template.name.clone(), template.clone());
+
+    HttpResponse::Ok().json(template)
+}
+
+// READ - 모든 템플릿 조회
+async fn get_templates(data: web::Data<AppState>) -> impl Responder {
+    let templates = data.templates.read().unwrap();
+    let mut template_list: Vec<&MemoTemplate> = templates.values().collect();
+    template_list.sort_by(|a, b| a.name.cmp(&b.name));
+    HttpResponse::Ok().json(template_list)
+}
+
+// CREATE - 템플릿으로 메모 생성
+// 내장 변수: date(2024-01-31), time(14:05), datetime, user(X-User 헤더)
+async fn create_memo_from_template(req: HttpRequest, data: web::Data<AppState>, name: web::Path<String>, from_req: web::Json<FromTemplateRequest>) -> impl Responder {
+    let Some(template) = data.templates.read().unwrap().get(name.as_str()).cloned() else {
+        return HttpResponse::NotFound().body("템플릿을 찾을 수 없습니다");
+    };
+    let from_req = from_req.into_inner();
+
+    let missing: Vec<String> = template.variables.iter().filter(|v| !from_req.variables.contains_key(*v)).cloned().collect();
+    if !missing.is_empty() {
+        return HttpResponse::BadRequest().json(MissingVariables { message: "템플릿 변수가 부족합니다".to_string(), missing });
+    }
+
+    let now = chrono::Local::now();
+    let actor = Actor::from_request(&req);
+    let mut values: HashMap<String, String> = HashMap::from([
+        ("date".to_string(), now.format("%Y-%m-%d").to_string()),
+        ("time".to_string(), now.format("%H:%M").to_string()),
+        ("datetime".to_string(), now.format("%Y-%m-%d %H:%M:%S").to_string()),
+        ("user".to_string(), actor.name.clone()),
+    ]);
+    values.extend(from_req.variables);
+
+    let memo_req = CreateMemoRequest {
+        title: fill_template(&template.title, &values),
+        content: fill_template(&template.content, &values),
+        due_at: None,
+        remind_at: None,
+        recurrence: None,
+        notebook_id: from_req.notebook_id,
+        expires_at: None,
+        ttl_seconds: None,
+    };
+
+    match insert_memo(&data, &actor, memo_req) {
+        Ok(memo) => HttpResponse::Ok().json(memo),
+        Err(message) => HttpResponse::BadRequest().body(message),
+    }
+}
+
+// READ - 모든 메모 조회
+async fn get_memos(data: web::Data<AppState>, query: web::Query<ListQuery>) -> impl Responder {
+    let mut memos = match data.reveal_all(data.memos.read().unwrap().values().filter(|memo| !memo.is_expired())) {
+        Ok(memos) => memos,
+        Err(message) => return HttpResponse::InternalServerError().body(message),
+    };
+    memos.sort_by_key(|memo| memo.id);
+
+    // 본문이 암호화돼 있을 수 있으므로 복호화한 뒤에 검색한다
+    if let Some(q) = query.q.as_deref().map(str::to_lowercase) {
+        memos.retain(|memo| memo.title.to_lowercase().contains(&q) || memo.content.to_lowercase().contains(&q));
+    }
+
+    memo_list_response(memos.iter().collect(), query.render.as_deref())
+}
+
+// READ - 마감 시각이 before 이전인 메모 조회 (마감 순 정렬)
+async fn get_due_memos(data: web::Data<AppState>, query: web::Query<DueQuery>) -> impl Responder {
+    let before = query.before.unwrap_or_else(Utc::now);
+    let memos = data.memos.read().unwrap();
+
+    let mut due = match data.reveal_all(memos.values().filter(|memo| !memo.is_expired() && memo.due_at.is_some_and(|due_at| due_at <= before))) {
+        Ok(due) => due,
+        Err(message) => return HttpResponse::InternalServerError().body(message),
+    };
+    due.sort_by_key(|memo| memo.due_at);
+
+    memo_list_response(due.iter().collect(), query.render.as_deref())
+}
+
+// READ - 모든 메모의 체크리스트 항목 조회
+async fn get_tasks(data: web::Data<AppState>, query: web::Query<TaskQuery>) -> impl Responder {
+    let memos = match data.reveal_all(data.memos.read().unwrap().values().filter(|memo| !memo.is_expired())) {
+        Ok(memos) => memos,
+        Err(message) => return HttpResponse::InternalServerError().body(message),
+    };
+
+    let mut tasks: Vec<TaskItem> = memos
+        .iter()
+        .flat_map(|memo| extract_tasks(&memo.content).into_iter().map(move |(text, checked)| TaskItem { memo_id: memo.id, memo_title: memo.title.clone(), text, checked }))
+        .filter(|task| query.checked.is_none_or(|checked| task.checked == checked))
+        .collect();
+    tasks.sort_by_key(|task| task.memo_id);
+
+    HttpResponse::Ok().json(tasks)
+}
+
+// 알림 구독 - 알림이 발생할 때마다 SSE 이벤트로 전달
+async fn stream_reminders(data: web::Data<AppState>) -> impl Responder {
+    let rx = data.reminders.subscribe();
+
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(reminder) => {
+                    let event = format!("event: reminder\ndata: {}\n\n", serde_json::to_string(&reminder).unwrap());
+                    return Some((Ok::<_, actix_web::Error>(Bytes::from(event)), rx));
+                }
+                // 구독자가 느려서 놓친 알림은 건너뛴다
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    HttpResponse::Ok().content_type("text/event-stream").insert_header(("Cache-Control", "no-cache")).streaming(stream)
+}
+
+// READ - 특정 메모 조회
+async fn get_memo(
+    data: web::Data<AppState>,
+    id: web::Path<u64>,
+) -> impl Responder {
+    let memos = data.memos.read().unwrap();
+    
+    match memos.get(&id.into_inner()).filter(|memo| !memo.is_expired()) {
+        Some(memo) => match data.reveal(memo) {
+            Ok(memo) => HttpResponse::Ok().json(memo),
+            Err(message) => HttpResponse::InternalServerError().body(message),
+        },
+        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
+    }
+}
+
+// READ - 특정 메모를 HTML 로 조회
+async fn get_memo_html(data: web::Data<AppState>, id: web::Path<u64>) -> impl Responder {
+    let memos = data.memos.read().unwrap();
+
+    match memos.get(&id.into_inner()).filter(|memo| !memo.is_expired()) {
+        Some(memo) => match data.reveal(memo) {
+            Ok(memo) => HttpResponse::Ok().content_type("text/html; charset=utf-8").body(render_markdown(&memo.content)),
+            Err(message) => HttpResponse::InternalServerError().body(message),
+        },
+        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
+    }
+}
+
+// READ - 메모가 가리키는 링크와 끊어진 링크 조회
+async fn get_memo_links(data: web::Data<AppState>, id: web::Path<u64>) -> impl Responder {
+    let id = id.into_inner();
+    let memos = data.memos.read().unwrap();
+    if memos.get(&id).is_none_or(Memo::is_expired) {
+        return HttpResponse::NotFound().body("메모를 찾을 수 없습니다");
+    }
+
+    let links = data.links.read().unwrap();
+    let titles = title_index(&memos);
+
+    let links: Vec<MemoLink> = links.get(&id).into_iter().flatten().map(|target| MemoLink { target: target.clone(), memo_id: resolve_link(target, &memos, &titles) }).collect();
+    let dangling = links.iter().filter(|link| link.memo_id.is_none()).map(|link| link.target.clone()).collect();
+
+    HttpResponse::Ok().json(MemoLinks { memo_id: id, links, dangling })
+}
+
+// 링크를 가진 메모와 그 링크 대상 (지워졌거나 만료된 메모의 링크는 건너뛴다)
+fn link_sources<'a>(memos: &'a HashMap<u64, Memo>, links: &'a HashMap<u64, Vec<String>>) -> impl Iterator<Item = (u64, &'a Memo, &'a Vec<String>)> {
+    links.iter().filter_map(|(source_id, targets)| memos.get(source_id).filter(|memo| !memo.is_expired()).map(|source| (*source_id, source, targets)))
+}
+
+// READ - 이 메모를 가리키는 다른 메모 조회
+async fn get_memo_backlinks(data: web::Data<AppState>, id: web::Path<u64>) -> impl Responder {
+    let id = id.into_inner();
+    let memos = data.memos.read().unwrap();
+    if memos.get(&id).is_none_or(Memo::is_expired) {
+        return HttpResponse::NotFound().body("메모를 찾을 수 없습니다");
+    }
+
+    let links = data.links.read().unwrap();
+    let titles = title_index(&memos);
+
+    let mut backlinks: Vec<LinkSource> = Vec::new();
+    for (source_id, source, targets) in link_sources(&memos, &links) {
+        for target in targets {
+            if resolve_link(target, &memos, &titles) == Some(id) {
+                backlinks.push(LinkSource { memo_id: source_id, title: source.title.clone(), target: target.clone() });
+            }
+        }
+    }
+    backlinks.sort_by_key(|backlink| backlink.memo_id);
+
+    HttpResponse::Ok().json(backlinks)
+}
+
+// READ - 전체 메모의 끊어진 링크 조회
+async fn get_dangling_links(data: web::Data<AppState>) -> impl Responder {
+    let memos = data.memos.read().unwrap();
+    let links = data.links.read().unwrap();
+    let titles = title_index(&memos);
+
+    let mut dangling: Vec<LinkSource> = Vec::new();
+    for (source_id, source, targets) in link_sources(&memos, &links) {
+        for target in targets.iter().filter(|target| resolve_link(target, &memos, &titles).is_none()) {
+            dangling.push(LinkSource { memo_id: source_id, title: source.title.clone(), target: target.clone() });
+        }
+    }
+    dangling.sort_by_key(|link| link.memo_id);
+
+    HttpResponse::Ok().json(dangling)
+}
+
+// UPDATE - 메모 수정
+async fn update_memo(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<u64>,
+    memo_req: web::Json<CreateMemoRequest>,
+) -> impl Responder {
+    match replace_memo(&data, &Actor::from_request(&req), id.into_inner(), memo_req.into_inner()) {
+        Ok(Some(memo)) => HttpResponse::Ok().json(memo),
+        Ok(None) => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
+        Err(message) => HttpResponse::BadRequest().body(message),
+    }
+}
+
+// DELETE - 메모 삭제
+async fn delete_memo(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<u64>,
+) -> impl Responder {
+    match remove_memo(&data, &Actor::from_request(&req), id.into_inner()) {
+        Some(_) => HttpResponse::Ok().body("메모가 삭제되었습니다"),
+        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
+    }
+}
+
+// 메모를 다른 노트북으로 이동
+async fn move_memo(req: HttpRequest, data: web::Data<AppState>, id: web::Path<u64>, move_req: web::Json<MoveMemoRequest>) -> impl Responder {
+    let notebooks = data.notebooks.read().unwrap();
+    if let Some(notebook_id) = move_req.notebook_id {
+        if !notebooks.contains_key(&notebook_id) {
+            return HttpResponse::BadRequest().body("노트북을 찾을 수 없습니다");
+        }
+    }
+
+    let mut memos = data.memos.write().unwrap();
+    match memos.get_mut(&id.into_inner()).filter(|memo| !memo.is_expired()) {
+        Some(memo) => {
+            let before = memo.clone();
+            memo.notebook_id = move_req.notebook_id;
+            data.record_audit(AuditAction::Update, &Actor::from_request(&req), memo.id, Some(&before), Some(memo));
+            match data.reveal(memo) {
+                Ok(memo) => HttpResponse::Ok().json(memo),
+                Err(message) => HttpResponse::InternalServerError().body(message),
+            }
+        }
+        None => HttpResponse::NotFound().body("메모를 찾을 수 없습니다"),
+    }
+}
+
+// CREATE - 새 노트북 생성
+async fn create_notebook(data: web::Data<AppState>, notebook_req: web::Json<NotebookRequest>) -> impl Responder {
+    let mut notebooks = data.notebooks.write().unwrap();
+    if let Some(parent_id) = notebook_req.parent_id {
+        if !notebooks.contains_key(&parent_id) {
+            return HttpResponse::BadRequest().body("상위 노트북을 찾을 수 없습니다");
+        }
+    }
+
+    let id = data.next_notebook_id.fetch_add(1, Ordering::Relaxed);
+    let notebook = Notebook { id, name: notebook_req.name.clone(), parent_id: notebook_req.parent_id, created_at: Utc::now() };
+    notebooks.insert(id, notebook.clone());
+
+    HttpResponse::Ok().json(notebook)
+}
+
+// READ - 모든 노트북 조회
+async fn get_notebooks(data: web::Data<AppState>) -> impl Responder {
+    let notebooks = data.notebooks.read().unwrap();
+    let mut notebook_list: Vec<&Notebook> = notebooks.values().collect();
+    notebook_list.sort_by_key(|notebook| notebook.id);
+    HttpResponse::Ok().json(notebook_list)
+}
+
+// READ - 노트북 내용 조회 (바로 아래 항목 또는 하위 전체)
+async fn get_notebook_contents(data: web::Data<AppState>, id: web::Path<u64>, query: web::Query<ContentsQuery>) -> impl Responder {
+    let id = id.into_inner();
+    let notebooks = data.notebooks.read().unwrap();
+    let Some(notebook) = notebooks.get(&id) else {
+        return HttpResponse::NotFound().body("노트북을 찾을 수 없습니다");
+    };
+
+    // 재귀 조회면 하위 트리 전체, 아니면 자기 자신만 부모로 본다
+    let parents = if query.recursive.unwrap_or(false) { notebook_subtree(&notebooks, id) } else { vec![id] };
+
+    let mut children: Vec<&Notebook> = notebooks.values().filter(|child| child.parent_id.is_some_and(|parent_id| parents.contains(&parent_id))).collect();
+    children.sort_by_key(|child| child.id);
+
+    let memos = data.memos.read().unwrap();
+    let mut memo_list = match data.reveal_all(memos.values().filter(|memo| !memo.is_expired() && memo.notebook_id.is_some_and(|notebook_id| parents.contains(&notebook_id)))) {
+        Ok(memo_list) => memo_list,
+        Err(message) => return HttpResponse::InternalServerError().body(message),
+    };
+    memo_list.sort_by_key(|memo| memo.id);
+
+    HttpResponse::Ok().json(NotebookContents { notebook, notebooks: children, memos: memo_list.iter().collect() })
+}
+
+// UPDATE - 노트북 이름 변경 / 다른 노트북 아래로 이동
+async fn update_notebook(data: web::Data<AppState>, id: web::Path<u64>, notebook_req: web::Json<NotebookRequest>) -> impl Responder {
+    let id = id.into_inner();
+    let mut notebooks = data.notebooks.write().unwrap();
+    if !notebooks.contains_key(&id) {
+        return HttpResponse::NotFound().body("노트북을 찾을 수 없습니다");
+    }
+
+    if let Some(parent_id) = notebook_req.parent_id {
+        if !notebooks.contains_key(&parent_id) {
+            return HttpResponse::BadRequest().body("상위 노트북을 찾을 수 없습니다");
+        }
+        // 자기 자신이나 자기 하위 노트북 아래로는 옮길 수 없다
+        if notebook_subtree(&notebooks, id).contains(&parent_id) {
+            return HttpResponse::BadRequest().body("노트북을 자신의 하위로 옮길 수 없습니다");
+        }
+    }
+
+    let notebook = notebooks.get_mut(&id).unwrap();
+    notebook.name = notebook_req.name.clone();
+    notebook.parent_id = notebook_req.parent_id;
+
+    HttpResponse::Ok().json(notebook)
+}
+
+// DELETE - 노트북 삭제 (cascade: 하위까지 삭제, reparent: 하위를 부모로 옮김)
+async fn delete_notebook(req: HttpRequest, data: web::Data<AppState>, id: web::Path<u64>, query: web::Query<DeleteNotebookQuery>) -> impl Responder {
+    let id = id.into_inner();
+    let mut notebooks = data.notebooks.write().unwrap();
+    let Some(notebook) = notebooks.remove(&id) else {
+        return HttpResponse::NotFound().body("노트북을 찾을 수 없습니다");
+    };
+
+    let mut memos = data.memos.write().unwrap();
+    let actor = Actor::from_request(&req);
+
+    match query.mode.unwrap_or(DeleteMode::Reparent) {
+        DeleteMode::Cascade => {
+            // 삭제한 노트북을 잠시 되돌려 하위 트리를 구한 뒤 한꺼번에 지운다
+            notebooks.insert(id, notebook);
+            let subtree = notebook_subtree(&notebooks, id);
+            notebooks.retain(|notebook_id, _| !subtree.contains(notebook_id));
+
+            let removed: Vec<u64> = memos.values().filter(|memo| memo.notebook_id.is_some_and(|notebook_id| subtree.contains(&notebook_id))).map(|memo| memo.id).collect();
+            let mut links = data.links.write().unwrap();
+            for memo_id in removed {
+                let memo = memos.remove(&memo_id).unwrap();
+                links.remove(&memo_id);
+                data.reindex_expiry(memo_id, memo.expires_at, None);
+                data.record_audit(AuditAction::Delete, &actor, memo_id, Some(&memo), None);
+            }
+        }
+        DeleteMode::Reparent => {
+            for child in notebooks.values_mut().filter(|child| child.parent_id == Some(id)) {
+                child.parent_id = notebook.parent_id;
+            }
+            for memo in memos.values_mut().filter(|memo| memo.notebook_id == Some(id)) {
+                let before = memo.clone();
+                memo.notebook_id = notebook.parent_id;
+                data.record_audit(AuditAction::Update, &actor, memo.id, Some(&before), Some(memo));
+            }
+        }
+    }
+
+    HttpResponse::Ok().body("노트북이 삭제되었습니다")
+}
+
+// READ - 감사 로그 조회
+async fn get_audit(data: web::Data<AppState>, query: web::Query<AuditQuery>) -> impl Responder {
+    let entries = match data.audit.read().unwrap().entries() {
+        Ok(entries) => entries,
+        Err(e) => return HttpResponse::InternalServerError().body(e),
+    };
+    let entries: Vec<&AuditEntry> = entries
+        .iter()
+        .filter(|entry| query.memo_id.is_none_or(|memo_id| entry.memo_id == memo_id))
+        .filter(|entry| query.since.is_none_or(|since| entry.timestamp >= since))
+        .collect();
+    HttpResponse::Ok().json(entries)
+}
+
+// 키 교체 요청 - 키는 모두 "<키 ID>:<base64 키>"
+// 지금 저장된 본문을 열 수 있도록 이전 키를 old_keys 에 함께 넣는다
+#[derive(Debug, Deserialize)]
+struct RotateKeysRequest {
+    active_key: String,
+    #[serde(default)]
+    old_keys: Vec<String>,
+}
+
+// 키 교체 결과 (새 키 ID, 다시 암호화한 메모 수)
+#[derive(Debug, Serialize)]
+struct RotateKeysResponse {
+    key_id: String,
+    rotated: usize,
+}
+
+// POST - 본문 암호화 키 교체 (X-Admin-Token 헤더 필요, MEMO_ADMIN_TOKEN 이 없으면 404)
+// 바꾼 키는 메모리에만 있으므로 재시작 뒤에도 쓰려면 MEMO_ENCRYPTION_KEY / MEMO_ENCRYPTION_OLD_KEYS 도 바꿔 둔다
+async fn rotate_keys(data: web::Data<AppState>, req: HttpRequest, rotate_req: web::Json<RotateKeysRequest>) -> impl Responder {
+    let Some(admin_token) = &data.admin_token else {
+        return HttpResponse::NotFound().finish();
+    };
+    if req.headers().get("X-Admin-Token").and_then(|v| v.to_str().ok()) != Some(admin_token.as_str()) {
+        return HttpResponse::Forbidden().body("관리자 토큰이 올바르지 않습니다");
+    }
+
+    let old_keys: Vec<&str> = rotate_req.old_keys.iter().map(String::as_str).collect();
+    let cipher = match ContentCipher::new(&rotate_req.active_key, &old_keys) {
+        Ok(cipher) => cipher,
+        Err(e) => return HttpResponse::BadRequest().body(e),
+    };
+    let key_id = cipher.key_id().to_string();
+    if let Err(e) = data.replace_cipher(cipher) {
+        return HttpResponse::BadRequest().body(e);
+    }
+
+    let rotated = run_key_rotation(data).await;
+    HttpResponse::Ok().json(RotateKeysResponse { key_id, rotated })
+}
+
+// 메모 API 라우트 등록 (App::new().app_data(state).configure(configure_routes))
+// 경로 접두사를 붙이려면 web::scope("/접두사").configure(configure_routes)
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.app_data(web::Data::new(graphql::schema()))
+        .route("/memos", web::post().to(create_memo))
+        .route("/memos", web::get().to(get_memos))
+        // {id} 보다 먼저 등록해야 id 로 해석되지 않는다
+        .route("/memos/due", web::get().to(get_due_memos))
+        .route("/memos/reminders", web::get().to(stream_reminders))
+        .route("/memos/tasks", web::get().to(get_tasks))
+        .route("/memos/links/dangling", web::get().to(get_dangling_links))
+        .route("/memos/from-template/{name}", web::post().to(create_memo_from_template))
+        .route("/memos/{id}", web::get().to(get_memo))
+        .route("/memos/{id}", web::put().to(update_memo))
+        .route("/memos/{id}", web::delete().to(delete_memo))
+        .route("/memos/{id}/html", web::get().to(get_memo_html))
+        .route("/memos/{id}/links", web::get().to(get_memo_links))
+        .route("/memos/{id}/backlinks", web::get().to(get_memo_backlinks))
+        .route("/memos/{id}/notebook", web::put().to(move_memo))
+        .route("/templates", web::post().to(create_template))
+        .route("/templates", web::get().to(get_templates))
+        .route("/notebooks", web::post().to(create_notebook))
+        .route("/notebooks", web::get().to(get_notebooks))
+        .route("/notebooks/{id}", web::get().to(get_notebook_contents))
+        .route("/notebooks/{id}", web::put().to(update_notebook))
+        .route("/notebooks/{id}", web::delete().to(delete_notebook))
+        .route("/audit", web::get().to(get_audit))
+        .route("/admin/rotate-keys", web::post().to(rotate_keys))
+        // GraphQL - POST 는 쿼리/뮤테이션, WebSocket 은 구독, 그 밖의 GET 은 GraphiQL
+        .route("/graphql", web::post().to(graphql::graphql))
+        .route("/graphql", web::get().guard(guard::Header("upgrade", "websocket")).to(graphql::graphql_ws))
+        .route("/graphql", web::get().to(graphql::graphiql));
+}
@@ -0,0 +1,65 @@
+use super::Memo;
+use serde::Serialize;
+use std::collections::HashMap;
+
+// 위키 링크 하나 ([[제목]] 또는 [[#42]]) 와 해석된 메모
+#[derive(Debug, Serialize)]
+pub struct MemoLink {
+    pub target: String,
+    // 가리키는 메모가 없으면(끊어진 링크) None
+    pub memo_id: Option<u64>,
+}
+
+// 메모가 가진 링크 목록과 끊어진 링크
+#[derive(Debug, Serialize)]
+pub struct MemoLinks {
+    pub memo_id: u64,
+    pub links: Vec<MemoLink>,
+    pub dangling: Vec<String>,
+}
+
+// 링크를 가진 메모와 그 링크 대상 (백링크, 끊어진 링크 목록에 사용)
+#[derive(Debug, Serialize)]
+pub struct LinkSource {
+    pub memo_id: u64,
+    pub title: String,
+    pub target: String,
+}
+
+// 본문에서 [[...]] 위키 링크 대상을 순서대로 추출 (중복 제거)
+pub fn parse_wiki_links(content: &str) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("[[") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("]]") else { break };
+
+        let target = rest[..end].trim();
+        // 줄을 넘는 괄호는 링크로 보지 않는다
+        if !target.is_empty() && !target.contains('\n') && !targets.iter().any(|t| t == target) {
+            targets.push(target.to_string());
+        }
+        rest = &rest[end + 2..];
+    }
+
+    targets
+}
+
+// 제목(소문자) → 메모 ID 색인. 같은 제목이 여러 개면 가장 먼저 만든 메모 (만료된 메모 제외)
+pub fn title_index(memos: &HashMap<u64, Memo>) -> HashMap<String, u64> {
+    let mut index: HashMap<String, u64> = HashMap::new();
+    for memo in memos.values().filter(|memo| !memo.is_expired()) {
+        let id = index.entry(memo.title.to_lowercase()).or_insert(memo.id);
+        *id = (*id).min(memo.id);
+    }
+    index
+}
+
+// 링크 대상을 메모 ID 로 해석 ([[#42]] 는 ID, 나머지는 제목)
+pub fn resolve_link(target: &str, memos: &HashMap<u64, Memo>, titles: &HashMap<String, u64>) -> Option<u64> {
+    if let Some(id) = target.strip_prefix('#').and_then(|id| id.parse::<u64>().ok()) {
+        return memos.get(&id).is_some_and(|memo| !memo.is_expired()).then_some(id);
+    }
+    titles.get(&target.to_lowercase()).copied()
+}
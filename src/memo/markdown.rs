@@ -0,0 +1,54 @@
+use pulldown_cmark::{html, Event, Options, Parser, Tag};
+
+// 메모 본문에 쓰는 CommonMark 확장 (표, 체크리스트, 취소선)
+fn markdown_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH
+}
+
+// 마크다운을 HTML 로 렌더링하고 스크립트 등 위험한 태그를 제거
+pub fn render_markdown(content: &str) -> String {
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, Parser::new_ext(content, markdown_options()));
+
+    // 체크리스트 체크박스(<input type="checkbox">)만 살려둔다
+    ammonia::Builder::default()
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            ("input", "type") if value != "checkbox" => None,
+            _ => Some(value.into()),
+        })
+        .clean(&unsafe_html)
+        .to_string()
+}
+
+// 마크다운 체크리스트 항목 추출 ((텍스트, 체크 여부) 목록)
+pub fn extract_tasks(content: &str) -> Vec<(String, bool)> {
+    let mut tasks = Vec::new();
+    // 중첩 목록을 위해 열린 항목마다 체크리스트 여부를 쌓아둔다
+    let mut items: Vec<Option<(String, bool)>> = Vec::new();
+
+    for event in Parser::new_ext(content, markdown_options()) {
+        match event {
+            Event::Start(Tag::Item) => items.push(None),
+            Event::TaskListMarker(checked) => {
+                if let Some(item) = items.last_mut() {
+                    *item = Some((String::new(), checked));
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(Some((item_text, _))) = items.last_mut() {
+                    item_text.push_str(&text);
+                }
+            }
+            Event::End(Tag::Item) => {
+                if let Some(Some((text, checked))) = items.pop() {
+                    tasks.push((text.trim().to_string(), checked));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tasks
+}
@@ -0,0 +1,68 @@
+use super::Memo;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 노트북(폴더) 구조체 - parent_id 로 중첩된다
+#[derive(Debug, Serialize, Clone)]
+pub struct Notebook {
+    pub id: u64,
+    pub name: String,
+    pub parent_id: Option<u64>,
+    pub created_at: DateTime<Utc>,
+}
+
+// 노트북 생성/수정 요청 (수정 시 parent_id 를 바꾸면 노트북이 이동한다)
+#[derive(Debug, Deserialize)]
+pub struct NotebookRequest {
+    pub name: String,
+    pub parent_id: Option<u64>,
+}
+
+// 메모를 다른 노트북으로 옮기는 요청 (None 이면 최상위로)
+#[derive(Debug, Deserialize)]
+pub struct MoveMemoRequest {
+    pub notebook_id: Option<u64>,
+}
+
+// 노트북 내용 조회 쿼리 (?recursive=true 면 하위 노트북 전체)
+#[derive(Debug, Deserialize)]
+pub struct ContentsQuery {
+    pub recursive: Option<bool>,
+}
+
+// 노트북 삭제 방식
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteMode {
+    // 하위 노트북과 메모까지 모두 삭제
+    Cascade,
+    // 하위 노트북과 메모를 부모 노트북으로 옮긴 뒤 삭제
+    Reparent,
+}
+
+// 노트북 삭제 쿼리 (?mode=cascade, 기본은 reparent)
+#[derive(Debug, Deserialize)]
+pub struct DeleteNotebookQuery {
+    pub mode: Option<DeleteMode>,
+}
+
+// 노트북 내용 응답
+#[derive(Debug, Serialize)]
+pub struct NotebookContents<'a> {
+    pub notebook: &'a Notebook,
+    pub notebooks: Vec<&'a Notebook>,
+    pub memos: Vec<&'a Memo>,
+}
+
+// root 와 그 아래 모든 하위 노트북 ID (root 포함)
+pub fn notebook_subtree(notebooks: &HashMap<u64, Notebook>, root: u64) -> Vec<u64> {
+    let mut subtree = vec![root];
+    let mut i = 0;
+    while i < subtree.len() {
+        let parent = subtree[i];
+        subtree.extend(notebooks.values().filter(|notebook| notebook.parent_id == Some(parent)).map(|notebook| notebook.id));
+        i += 1;
+    }
+    subtree
+}
@@ -0,0 +1,111 @@
+use super::audit::{Actor, AuditAction};
+use super::AppState;
+use actix_web::web;
+use chrono::{DateTime, Utc};
+use notify_rust::Notification;
+use serde::Serialize;
+use std::time::Duration;
+
+// 스케줄러가 발생시키는 알림 이벤트
+#[derive(Debug, Serialize, Clone)]
+pub struct Reminder {
+    pub memo_id: u64,
+    pub title: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub remind_at: DateTime<Utc>,
+}
+
+// 알림 시각이 지난 메모를 찾아 알림을 만들고, 반복 규칙에 따라 다음 알림 시각을 정한다
+fn collect_reminders(data: &AppState, now: DateTime<Utc>) -> Vec<Reminder> {
+    let mut memos = data.memos.write().unwrap();
+    let mut fired = Vec::new();
+    let actor = Actor::system("scheduler");
+
+    // 만료된 메모는 알림을 보내지 않는다
+    for memo in memos.values_mut().filter(|memo| !memo.is_expired()) {
+        let remind_at = match memo.remind_at {
+            Some(remind_at) if remind_at <= now => remind_at,
+            _ => continue,
+        };
+        let before = memo.clone();
+
+        fired.push(Reminder { memo_id: memo.id, title: memo.title.clone(), due_at: memo.due_at, remind_at });
+
+        match memo.recurrence {
+            // 반복 메모는 알림/마감 시각을 현재 이후가 될 때까지 같은 간격만큼 민다
+            Some(recurrence) => {
+                let mut next = remind_at;
+                while next <= now {
+                    next += recurrence.interval();
+                    memo.due_at = memo.due_at.map(|due_at| due_at + recurrence.interval());
+                }
+                memo.remind_at = Some(next);
+            }
+            None => memo.remind_at = None,
+        }
+
+        // 알림 시각 변경도 메모 수정이므로 감사 로그에 남긴다
+        data.record_audit(AuditAction::Update, &actor, memo.id, Some(&before), Some(memo));
+    }
+
+    fired
+}
+
+type WebhookClient = hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>;
+
+// 웹훅 하나를 기다리는 최대 시간 (느린 웹훅이 다른 알림을 막지 않도록)
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 웹훅으로 알림 전송 (REMINDER_WEBHOOK_URL)
+async fn send_webhook(client: &WebhookClient, url: &str, reminder: &Reminder) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let req = hyper::Request::builder().method(hyper::Method::POST).uri(url).header("Content-Type", "application/json").body(hyper::Body::from(serde_json::to_vec(reminder)?))?;
+
+    let res = client.request(req).await?;
+    if !res.status().is_success() {
+        return Err(format!("웹훅 응답 오류: {}", res.status()).into());
+    }
+
+    Ok(())
+}
+
+// 백그라운드 알림 스케줄러
+// - SSE 구독자에게는 항상 전달
+// - REMINDER_WEBHOOK_URL 이 있으면 웹훅 호출
+// - REMINDER_DESKTOP=1 이면 데스크톱 알림 (mouse_move 와 같은 notify-rust 사용)
+pub async fn run_reminder_scheduler(data: web::Data<AppState>) {
+    // 웹훅 클라이언트는 하나만 만들어 연결을 재사용한다
+    let webhook = std::env::var("REMINDER_WEBHOOK_URL").ok().map(|url| {
+        let client: WebhookClient = hyper::Client::builder().build(hyper_rustls::HttpsConnectorBuilder::new().with_native_roots().https_or_http().enable_http1().build());
+        (url, client)
+    });
+    let desktop = std::env::var("REMINDER_DESKTOP").is_ok_and(|v| v == "1");
+    let seconds = std::env::var("REMINDER_INTERVAL_SECONDS").ok().and_then(|s| s.parse().ok()).unwrap_or(10);
+
+    let mut interval = tokio::time::interval(Duration::from_secs(seconds));
+
+    loop {
+        interval.tick().await;
+
+        for reminder in collect_reminders(&data, Utc::now()) {
+            // 구독자가 없으면 에러지만 무시해도 된다
+            data.reminders.send(reminder.clone()).ok();
+
+            // 웹훅은 따로 보내서 응답을 기다리는 동안 다음 알림이 밀리지 않게 한다
+            if let Some((url, client)) = &webhook {
+                let (url, client, reminder) = (url.clone(), client.clone(), reminder.clone());
+                tokio::spawn(async move {
+                    match tokio::time::timeout(WEBHOOK_TIMEOUT, send_webhook(&client, &url, &reminder)).await {
+                        Ok(Ok(())) => {}
+                        Ok(Err(e)) => eprintln!("웹훅 전송 실패 (메모 {}): {}", reminder.memo_id, e),
+                        Err(_) => eprintln!("웹훅 전송 실패 (메모 {}): {}초 안에 응답이 없습니다", reminder.memo_id, WEBHOOK_TIMEOUT.as_secs()),
+                    }
+                });
+            }
+
+            if desktop {
+                let title = reminder.title.clone();
+                tokio::task::spawn_blocking(move || Notification::new().summary("메모 알림").body(&title).show().map(|_| ()).map_err(|e| eprintln!("데스크톱 알림 실패: {}", e)));
+            }
+        }
+    }
+}
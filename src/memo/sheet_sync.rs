@@ -0,0 +1,281 @@
+use super::audit::Actor;
+use super::{insert_memo_as, replace_memo, AppState, CreateMemoRequest, Memo};
+use crate::sheets::{quote_sheet, SheetsBackend, SheetsClient};
+use actix_web::web;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+// 메모 ↔ 구글 시트 양방향 동기화
+// 시트 열: id | title | content | created_at | updated_at | sync_hash
+// - updated_at: 이 행을 마지막으로 쓸 때 메모의 updated_at
+// - sync_hash: 이 행을 마지막으로 쓸 때 제목/본문의 해시 (시트에서 고쳤는지 알아내는 데 사용, 수정 금지)
+// 동기화할 때마다
+// - 시트에서만 고친 행은 메모에 반영하고
+// - 메모만 바뀌었으면 시트에 다시 쓰고
+// - 둘 다 바뀌었으면 충돌로 보고 메모를 남긴다 (시트 내용은 로그에 출력)
+// - id 가 빈 행은 새 메모로 만든다 (ID 를 시트에 먼저 쓰고 쓰기가 성공한 뒤에 저장하므로,
+//   시트 쓰기에 실패해도 다음 동기화 때 같은 행으로 메모가 또 만들어지지 않는다)
+// 시트 전체를 메모 기준으로 다시 쓰므로, 시트에서 지운 행은 다음 동기화 때 되살아난다
+// 시트에는 본문이 평문으로 들어가므로 본문 암호화(MEMO_ENCRYPTION_KEY)를 켜면 동기화하지 않는다
+
+pub type SyncError = Box<dyn std::error::Error + Send + Sync>;
+
+const HEADER: [&str; 6] = ["id", "title", "content", "created_at", "updated_at", "sync_hash"];
+
+// 동기화가 읽고 쓰는 시트 (구글 시트 또는 테스트용 메모리 시트)
+#[async_trait]
+pub trait SheetStore {
+    // 머리글을 포함한 모든 행
+    async fn read_rows(&self) -> Result<Vec<Vec<String>>, SyncError>;
+    // 시트 내용을 rows 로 교체
+    async fn write_rows(&mut self, rows: Vec<Vec<String>>) -> Result<(), SyncError>;
+}
+
+// 테스트용 메모리 시트
+#[derive(Debug, Default)]
+pub struct MemorySheet {
+    pub rows: Vec<Vec<String>>,
+}
+
+#[async_trait]
+impl SheetStore for MemorySheet {
+    async fn read_rows(&self) -> Result<Vec<Vec<String>>, SyncError> {
+        Ok(self.rows.clone())
+    }
+
+    async fn write_rows(&mut self, rows: Vec<Vec<String>>) -> Result<(), SyncError> {
+        self.rows = rows;
+        Ok(())
+    }
+}
+
+// 스프레드시트의 시트 하나 (기본은 GOOGLE_SERVICE_ACCOUNT_KEY 서비스 계정으로 인증한 구글 시트)
+// 인증한 클라이언트를 계속 쓰므로 동기화할 때마다 재인증하지 않는다
+// 테스트에서는 InMemorySheets 를 백엔드로 쓸 수 있다
+pub struct GoogleSheet<B: SheetsBackend = SheetsClient> {
+    backend: B,
+    spreadsheet_id: String,
+    sheet_name: String,
+}
+
+impl GoogleSheet<SheetsClient> {
+    pub async fn connect(spreadsheet_id: &str, sheet_name: &str) -> Result<Self, SyncError> {
+        Ok(GoogleSheet::new(SheetsClient::from_env().await?, spreadsheet_id, sheet_name))
+    }
+}
+
+impl<B: SheetsBackend> GoogleSheet<B> {
+    pub fn new(backend: B, spreadsheet_id: &str, sheet_name: &str) -> Self {
+        GoogleSheet { backend, spreadsheet_id: spreadsheet_id.to_string(), sheet_name: sheet_name.to_string() }
+    }
+}
+
+#[async_trait]
+impl<B: SheetsBackend> SheetStore for GoogleSheet<B> {
+    async fn read_rows(&self) -> Result<Vec<Vec<String>>, SyncError> {
+        let range = format!("{}!A:F", quote_sheet(&self.sheet_name));
+        Ok(self.backend.get_values(&self.spreadsheet_id, &range).await?)
+    }
+
+    // 지우고 쓰면 쓰기에 실패했을 때 시트가 비므로, 한 번의 쓰기로 전체를 덮는다
+    // 남는 행은 빈 값으로 덮어 지우고, 모든 행을 열 수만큼 채워 이전 값이 남지 않게 한다
+    async fn write_rows(&mut self, mut rows: Vec<Vec<String>>) -> Result<(), SyncError> {
+        let old_len = self.read_rows().await?.len();
+        let height = rows.len().max(old_len);
+        rows.resize_with(height, Vec::new);
+        for row in &mut rows {
+            row.resize(HEADER.len(), String::new());
+        }
+
+        let range = format!("{}!A1:F{}", quote_sheet(&self.sheet_name), height);
+        self.backend.update_values(&self.spreadsheet_id, &range, rows).await?;
+        Ok(())
+    }
+}
+
+// 한 번 동기화한 결과
+#[derive(Debug, Default, PartialEq)]
+pub struct SyncReport {
+    // 시트에 새로 쓰거나 고쳐 쓴 메모 수
+    pub pushed: usize,
+    // 시트에서 고친 내용을 반영한 메모 수
+    pub pulled: usize,
+    // 시트의 새 행으로 만든 메모 수
+    pub created: usize,
+    // 양쪽에서 모두 고쳐 메모를 남긴 메모 ID
+    pub conflicts: Vec<u64>,
+}
+
+// 시트 한 행
+struct SheetRow {
+    id: Option<u64>,
+    title: String,
+    content: String,
+    synced_at: Option<DateTime<Utc>>,
+    sync_hash: String,
+}
+
+impl SheetRow {
+    fn parse(cells: &[String]) -> Self {
+        let cell = |i: usize| cells.get(i).cloned().unwrap_or_default();
+        SheetRow {
+            id: cell(0).trim().parse().ok(),
+            title: cell(1),
+            content: cell(2),
+            synced_at: DateTime::parse_from_rfc3339(cell(4).trim()).ok().map(|t| t.with_timezone(&Utc)),
+            sync_hash: cell(5),
+        }
+    }
+
+    // 마지막 동기화 뒤 시트에서 제목/본문을 고쳤는지
+    fn edited(&self) -> bool {
+        content_hash(&self.title, &self.content) != self.sync_hash
+    }
+}
+
+fn content_hash(title: &str, content: &str) -> String {
+    let digest = format!("{:x}", Sha256::digest(format!("{}\0{}", title, content)));
+    digest[..16].to_string()
+}
+
+fn memo_row(memo: &Memo) -> Vec<String> {
+    vec![memo.id.to_string(), memo.title.clone(), memo.content.clone(), memo.created_at.to_rfc3339(), memo.updated_at.to_rfc3339(), content_hash(&memo.title, &memo.content)]
+}
+
+// merge_sheet 결과 - 시트에 다시 쓸 행, 보고서, 시트를 쓴 뒤에 저장할 새 메모
+struct Merged {
+    rows: Vec<Vec<String>>,
+    report: SyncReport,
+    created: Vec<Memo>,
+}
+
+// 시트 행을 메모에 반영하고 시트에 다시 쓸 행을 만든다 (복호화에 실패하면 에러)
+// id 가 빈 행은 ID 만 할당해 시트 행에 넣고, 메모는 아직 저장하지 않는다
+fn merge_sheet(data: &AppState, rows: &[Vec<String>]) -> Result<Merged, String> {
+    let actor = Actor::system("sheet-sync");
+    let mut report = SyncReport::default();
+    let mut sheet_rows: HashMap<u64, SheetRow> = HashMap::new();
+    let mut created: Vec<Memo> = Vec::new();
+
+    for cells in rows.iter().filter(|cells| cells.first().map(String::as_str) != Some("id")) {
+        let row = SheetRow::parse(cells);
+        match row.id {
+            Some(id) => {
+                sheet_rows.insert(id, row);
+            }
+            // id 가 빈 행은 시트에서 새로 쓴 메모
+            // 쓰기에 실패하면 할당한 ID 는 건너뛴다 (다음 동기화 때 새 ID 를 받는다)
+            None if !row.title.is_empty() => {
+                let now = Utc::now();
+                let id = data.next_id.fetch_add(1, Ordering::Relaxed);
+                created.push(Memo { id, title: row.title, content: row.content, created_at: now, updated_at: now, due_at: None, remind_at: None, recurrence: None, notebook_id: None, expires_at: None });
+            }
+            None => {}
+        }
+    }
+
+    // 시트에서 고친 내용 반영 (메모도 그 뒤에 바뀌었으면 충돌)
+    let memos = data.reveal_all(data.memos.read().unwrap().values().filter(|memo| !memo.is_expired()))?;
+    for memo in &memos {
+        let Some(row) = sheet_rows.get(&memo.id) else { continue };
+        if !row.edited() || (row.title == memo.title && row.content == memo.content) {
+            continue;
+        }
+
+        if row.synced_at.is_some_and(|synced_at| memo.updated_at <= synced_at) {
+            let memo_req = CreateMemoRequest { title: row.title.clone(), content: row.content.clone(), due_at: memo.due_at, remind_at: memo.remind_at, recurrence: memo.recurrence, notebook_id: None, expires_at: None, ttl_seconds: None };
+            match replace_memo(data, &actor, memo.id, memo_req) {
+                Ok(Some(_)) => report.pulled += 1,
+                Ok(None) => {}
+                Err(e) => eprintln!("메모 {} 에 시트 내용을 반영할 수 없습니다: {}", memo.id, e),
+            }
+        } else {
+            eprintln!("메모 {} 충돌: 메모를 유지하고 시트 내용은 버립니다\n  시트 제목: {}\n  시트 본문: {}", memo.id, row.title, row.content);
+            report.conflicts.push(memo.id);
+        }
+    }
+
+    // 메모 기준으로 시트 전체를 다시 만든다 (서버에서 지우거나 만료된 메모의 행은 빠진다)
+    let mut memos = data.reveal_all(data.memos.read().unwrap().values().filter(|memo| !memo.is_expired()))?;
+    memos.extend(created.iter().cloned());
+    memos.sort_by_key(|memo| memo.id);
+
+    let created_ids: HashSet<u64> = created.iter().map(|memo| memo.id).collect();
+    let mut out = vec![HEADER.iter().map(|h| h.to_string()).collect::<Vec<_>>()];
+    for memo in &memos {
+        let changed = sheet_rows.get(&memo.id).is_none_or(|row| row.title != memo.title || row.content != memo.content);
+        if changed && !created_ids.contains(&memo.id) {
+            report.pushed += 1;
+        }
+        out.push(memo_row(memo));
+    }
+
+    Ok(Merged { rows: out, report, created })
+}
+
+// 암호화한 본문을 평문으로 내보내지 않도록 동기화를 거부할 때의 메시지
+const ENCRYPTED_SYNC_ERROR: &str = "본문 암호화가 켜져 있으면 시트에 평문이 들어가므로 동기화하지 않습니다";
+
+// 한 번 동기화 (시트를 읽고, 메모에 반영하고, 바뀌었으면 시트를 다시 쓴다)
+pub async fn sync_sheet<S: SheetStore>(data: &AppState, sheet: &mut S) -> Result<SyncReport, SyncError> {
+    if data.cipher.read().unwrap().is_some() {
+        return Err(ENCRYPTED_SYNC_ERROR.into());
+    }
+
+    let rows = sheet.read_rows().await?;
+    let Merged { rows: new_rows, mut report, created } = merge_sheet(data, &rows)?;
+    if new_rows != rows {
+        sheet.write_rows(new_rows).await?;
+    }
+
+    // 새 행의 ID 가 시트에 들어간 뒤에 메모를 저장한다 (시트에 쓴 생성 시각 그대로)
+    let actor = Actor::system("sheet-sync");
+    for memo in created {
+        let reserved = Some((memo.id, memo.created_at));
+        let memo_req = CreateMemoRequest { title: memo.title, content: memo.content, due_at: None, remind_at: None, recurrence: None, notebook_id: None, expires_at: None, ttl_seconds: None };
+        match insert_memo_as(data, &actor, memo_req, reserved) {
+            Ok(_) => report.created += 1,
+            Err(e) => eprintln!("시트 행으로 메모를 만들 수 없습니다: {}", e),
+        }
+    }
+
+    Ok(report)
+}
+
+// 백그라운드 시트 동기화
+// - MEMO_SYNC_SPREADSHEET_ID: 동기화할 스프레드시트 (없으면 동기화하지 않는다)
+// - MEMO_SYNC_SHEET: 시트 이름 (기본 memos)
+// - MEMO_SYNC_INTERVAL_SECONDS: 동기화 간격 (기본 60)
+// 본문 암호화를 켰으면 시작하지 않는다
+pub async fn run_sheet_sync(data: web::Data<AppState>) {
+    let Ok(spreadsheet_id) = std::env::var("MEMO_SYNC_SPREADSHEET_ID") else { return };
+    if data.cipher.read().unwrap().is_some() {
+        eprintln!("시트 동기화를 시작할 수 없습니다: {}", ENCRYPTED_SYNC_ERROR);
+        return;
+    }
+    let sheet_name = std::env::var("MEMO_SYNC_SHEET").unwrap_or_else(|_| "memos".to_string());
+    let seconds = std::env::var("MEMO_SYNC_INTERVAL_SECONDS").ok().and_then(|s| s.parse().ok()).unwrap_or(60);
+
+    let mut sheet = match GoogleSheet::connect(&spreadsheet_id, &sheet_name).await {
+        Ok(sheet) => sheet,
+        Err(e) => {
+            eprintln!("시트 동기화를 시작할 수 없습니다: {}", e);
+            return;
+        }
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(seconds));
+    loop {
+        interval.tick().await;
+
+        match sync_sheet(&data, &mut sheet).await {
+            Ok(report) if report != SyncReport::default() => println!("시트 동기화: {:?}", report),
+            Ok(_) => {}
+            Err(e) => eprintln!("시트 동기화 실패: {}", e),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 메모 템플릿 저장 요청 ({{변수}} 자리표시자 사용)
+#[derive(Debug, Deserialize)]
+pub struct TemplateRequest {
+    pub name: String,
+    pub title: String,
+    pub content: String,
+}
+
+// 저장된 템플릿 (variables: 사용자가 채워야 하는 변수 목록, 내장 변수 제외)
+#[derive(Debug, Serialize, Clone)]
+pub struct MemoTemplate {
+    pub name: String,
+    pub title: String,
+    pub content: String,
+    pub variables: Vec<String>,
+}
+
+// 템플릿으로 메모 생성 요청
+#[derive(Debug, Deserialize)]
+pub struct FromTemplateRequest {
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+    pub notebook_id: Option<u64>,
+}
+
+// 템플릿 변수가 빠졌을 때의 응답
+#[derive(Debug, Serialize)]
+pub struct MissingVariables {
+    pub message: String,
+    pub missing: Vec<String>,
+}
+
+// 템플릿 내장 변수 (요청에서 같은 이름을 주면 덮어쓴다)
+const BUILTIN_VARIABLES: [&str; 4] = ["date", "time", "datetime", "user"];
+
+// 텍스트를 문자열 조각과 {{변수}} 로 나눈다 (변수 이름은 영문/숫자/_/-/. 만 허용)
+fn split_placeholders(text: &str) -> Vec<(bool, &str)> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        let name = rest[start + 2..start + 2 + len].trim();
+
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || "_-.".contains(c)) {
+            // 변수 모양이 아니면 "{{" 를 글자 그대로 두고 계속 찾는다
+            parts.push((false, &rest[..start + 2]));
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        parts.push((false, &rest[..start]));
+        parts.push((true, name));
+        rest = &rest[start + 2 + len + 2..];
+    }
+
+    parts.push((false, rest));
+    parts
+}
+
+// 템플릿에서 사용자가 채워야 하는 변수 목록 (등장 순서, 중복/내장 변수 제외)
+pub fn template_variables(texts: &[&str]) -> Vec<String> {
+    let mut variables: Vec<String> = Vec::new();
+    for text in texts {
+        for (is_var, name) in split_placeholders(text) {
+            if is_var && !BUILTIN_VARIABLES.contains(&name) && !variables.iter().any(|v| v == name) {
+                variables.push(name.to_string());
+            }
+        }
+    }
+    variables
+}
+
+// {{변수}} 를 값으로 치환 (모든 변수가 있다고 가정)
+pub fn fill_template(text: &str, values: &HashMap<String, String>) -> String {
+    split_placeholders(text).into_iter().map(|(is_var, part)| if is_var { values[part].as_str() } else { part }).collect()
+}
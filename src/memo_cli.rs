@@ -0,0 +1,74 @@
+use std::error::Error;
+
+// 메모 명령줄 클라이언트(src/bin/memo.rs)의 옵션 파싱과 출력 도우미
+// 서버와 주고받지 않는 부분만 모아 tests/memo_cli.rs 에서 확인한다
+
+pub const USAGE: &str = "사용법: memo [--url URL] [--token TOKEN] [--json] <명령>
+
+명령:
+  list                         메모 목록
+  get <id>                     메모 조회
+  new <제목>                   $EDITOR 로 본문을 작성해서 메모 생성
+  edit <id>                    $EDITOR 로 본문 수정
+  rm <id>                      메모 삭제
+  search <검색어>              제목/본문 검색
+  export [--format json|md] [--out 경로]
+                               전체 메모 내보내기 (json: 파일 또는 표준출력, md: 디렉터리)";
+
+pub type CliResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+// 명령줄 옵션
+pub struct Options {
+    pub url: String,
+    pub token: Option<String>,
+    pub json: bool,
+    pub format: String,
+    pub out: Option<String>,
+    // 옵션을 뺀 나머지 (명령과 인자)
+    pub args: Vec<String>,
+}
+
+// 옵션 파싱 (--이름 값 / --json)
+pub fn parse_options(raw: Vec<String>) -> CliResult<Options> {
+    let mut options = Options {
+        url: std::env::var("MEMO_URL").unwrap_or_else(|_| "http://localhost:8080".to_string()),
+        token: std::env::var("MEMO_TOKEN").ok(),
+        json: false,
+        format: "json".to_string(),
+        out: None,
+        args: Vec::new(),
+    };
+
+    let mut iter = raw.into_iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| iter.next().ok_or_else(|| format!("{} 에 값이 필요합니다", name));
+        match arg.as_str() {
+            "--url" => options.url = value("--url")?,
+            "--token" => options.token = Some(value("--token")?),
+            "--format" => options.format = value("--format")?,
+            "--out" => options.out = Some(value("--out")?),
+            "--json" => options.json = true,
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ => options.args.push(arg),
+        }
+    }
+
+    options.url = options.url.trim_end_matches('/').to_string();
+    Ok(options)
+}
+
+// 문자 수 기준으로 자르기 (한글이 깨지지 않도록)
+pub fn truncate(text: &str, width: usize) -> String {
+    let first_line = text.lines().next().unwrap_or("");
+    if first_line.chars().count() <= width {
+        first_line.to_string()
+    } else {
+        format!("{}…", first_line.chars().take(width - 1).collect::<String>())
+    }
+}
+
+// 파일 이름으로 쓸 수 있게 제목 정리
+pub fn slugify(title: &str) -> String {
+    let slug: String = title.chars().map(|c| if c.is_alphanumeric() { c } else { '-' }).collect();
+    slug.trim_matches('-').to_string()
+}
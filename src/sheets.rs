@@ -0,0 +1,155 @@
+// 구글 시트 - spreadsheet, spreadsheet_create, mouse_move, 메모 시트 동기화에서 공통 사용
+// - SheetsBackend: 시트 작업 트레이트
+// - SheetsClient: 실제 구글 Sheets API (한 번 인증해서 재사용)
+// - InMemorySheets: 자격 증명/네트워크 없이 테스트할 때 쓰는 메모리 구현
+// - configure_standin_routes: InMemorySheets 를 REST API 로 내보내는 대역 서버 (sheets_standin)
+// - read_rows: 헤더 행으로 필드를 맞춰 시트 행을 구조체로 읽기
+// - SheetRow: #[derive(SheetRow)] 로 구조체를 시트 행으로 (sheet_row_derive 크레이트)
+// - WorkbookSchema / provision_workbook: TOML 스키마대로 워크북 만들기, 다시 실행하면 빠진 시트/열만 추가 (spreadsheet_create)
+mod a1;
+mod cell;
+mod client;
+mod memory;
+mod project;
+mod provision;
+mod rows;
+mod schema;
+mod standin;
+
+use async_trait::async_trait;
+use google_sheets4::api::{AddSheetRequest, AppendValuesResponse, Request, Response, SheetProperties};
+use std::fmt;
+
+pub use a1::quote_sheet;
+pub use cell::{FormatCell, FromCell, ToCell};
+pub use client::SheetsClient;
+pub use memory::InMemorySheets;
+pub use project::{create_project_sheets, read_tasks, ProjectOverview, RoleResponsibility, Task, PROJECT_SHEETS};
+pub use provision::{apply_plan, plan_workbook, provision_workbook, SheetPlan, WorkbookPlan};
+pub use rows::{append_sheet_rows, date_cell, optional_date_cell, parse_row_cell, read_rows, read_sheet_rows, CellError, FieldError, SheetRow};
+pub use schema::{ColumnSchema, ColumnType, HighlightSchema, SheetSchema, WorkbookSchema};
+pub use sheet_row_derive::SheetRow;
+pub use standin::configure_standin_routes;
+
+// 시트 작업 에러 (Box<dyn Error> 와 Box<dyn Error + Send + Sync> 모두로 ? 변환된다)
+#[derive(Debug)]
+pub enum SheetsError {
+    // GOOGLE_SERVICE_ACCOUNT_KEY 가 없음
+    MissingKey,
+    // 키 파일을 읽거나 인증기를 만들 수 없음
+    Auth(std::io::Error),
+    // Sheets API 호출 실패 (에러 타입이 커서 Result 가 커지지 않도록 박스에 담는다)
+    Api(Box<google_sheets4::Error>),
+    // 응답에 있어야 할 값이 없음
+    MissingField(&'static str),
+    // 스프레드시트를 찾을 수 없음
+    NotFound(String),
+    // 잘못된 범위, 이미 있는 시트 이름 등 (API 의 400 응답에 해당)
+    BadRequest(String),
+    // read_rows 에서 읽을 수 없는 셀 (행마다 하나씩)
+    InvalidRows(Vec<CellError>),
+    // 워크북 스키마 파일을 읽을 수 없거나 잘못됨
+    InvalidSchema(String),
+}
+
+impl fmt::Display for SheetsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SheetsError::MissingKey => write!(f, "GOOGLE_SERVICE_ACCOUNT_KEY 가 설정되지 않았습니다"),
+            SheetsError::Auth(e) => write!(f, "서비스 계정 인증 실패: {}", e),
+            SheetsError::Api(e) => write!(f, "Sheets API 호출 실패: {}", e),
+            SheetsError::MissingField(field) => write!(f, "Sheets API 응답에 {} 가 없습니다", field),
+            SheetsError::NotFound(spreadsheet_id) => write!(f, "스프레드시트를 찾을 수 없습니다: {}", spreadsheet_id),
+            SheetsError::BadRequest(message) => write!(f, "잘못된 요청: {}", message),
+            SheetsError::InvalidRows(errors) => write!(f, "시트 데이터를 읽을 수 없습니다: {}", errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")),
+            SheetsError::InvalidSchema(message) => write!(f, "워크북 스키마 오류: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SheetsError {}
+
+impl From<google_sheets4::Error> for SheetsError {
+    fn from(e: google_sheets4::Error) -> Self {
+        SheetsError::Api(Box::new(e))
+    }
+}
+
+// 스프레드시트 작업 (범위는 "시트!A1:B2" 같은 A1 표기)
+// 구현체는 필수 메서드만 만들면 되고, 시트 추가/생성은 batch_update 위에서 동작한다
+#[async_trait]
+pub trait SheetsBackend: Send + Sync {
+    // range 안의 표 마지막 행 아래에 행 추가
+    async fn append_values(&self, spreadsheet_id: &str, range: &str, values: Vec<Vec<String>>) -> Result<AppendValuesResponse, SheetsError>;
+
+    // range 왼쪽 위부터 값 덮어쓰기
+    async fn update_values(&self, spreadsheet_id: &str, range: &str, values: Vec<Vec<String>>) -> Result<(), SheetsError>;
+
+    // range 의 값 읽기 (숫자 등으로 바뀐 셀도 문자열로, 끝의 빈 셀/행은 빠진다)
+    async fn get_values(&self, spreadsheet_id: &str, range: &str) -> Result<Vec<Vec<String>>, SheetsError>;
+
+    // range 의 값 지우기
+    async fn clear_values(&self, spreadsheet_id: &str, range: &str) -> Result<(), SheetsError>;
+
+    // 새 스프레드시트를 만들고 ID 를 돌려준다
+    async fn create_spreadsheet(&self, title: &str) -> Result<String, SheetsError>;
+
+    // 스프레드시트의 시트 목록 (시트 순서대로, sheet_id/title/index 등)
+    async fn get_sheets(&self, spreadsheet_id: &str) -> Result<Vec<SheetProperties>, SheetsError>;
+
+    // 요청을 순서대로 한꺼번에 적용하고 요청마다 응답을 돌려준다 (하나라도 실패하면 아무것도 바뀌지 않는다)
+    async fn batch_update(&self, spreadsheet_id: &str, requests: Vec<Request>) -> Result<Vec<Response>, SheetsError>;
+
+    // 새 시트를 추가하고 시트 ID 를 돌려준다
+    async fn add_sheet(&self, spreadsheet_id: &str, sheet_title: &str) -> Result<i32, SheetsError> {
+        let replies = self.batch_update(spreadsheet_id, vec![add_sheet_request(sheet_title)]).await?;
+        replies
+            .into_iter()
+            .next()
+            .and_then(|reply| reply.add_sheet)
+            .and_then(|reply| reply.properties)
+            .and_then(|properties| properties.sheet_id)
+            .ok_or(SheetsError::MissingField("addSheet.properties.sheetId"))
+    }
+
+    // 새 시트를 추가하고 헤더를 쓴 뒤 헤더 행 고정 + 헤더 수만큼 기본 필터 (시트 ID 를 돌려준다)
+    // 필터/고정은 AddSheet 응답의 시트 ID 로 보낸다 (시트 ID 가 없으면 첫 시트에 적용된다)
+    // 열 너비 같은 스키마 설정은 apply_plan 이 같은 방식으로 붙인다 (provision.rs)
+    async fn create_sheet(&self, spreadsheet_id: &str, sheet_title: &str, headers: Vec<String>) -> Result<i32, SheetsError> {
+        if headers.is_empty() {
+            return Err(SheetsError::BadRequest(format!("{} 시트의 헤더가 없습니다", sheet_title)));
+        }
+        let columns = headers.len();
+        let sheet_id = self.create_sheet_with_headers(spreadsheet_id, sheet_title, headers).await?;
+        self.batch_update(spreadsheet_id, vec![provision::frozen_rows_request(sheet_id, 1), provision::filter_request(sheet_id, columns)]).await?;
+        Ok(sheet_id)
+    }
+
+    // 새 시트를 추가하고 첫 행에 헤더를 쓴 뒤 시트 ID 를 돌려준다
+    async fn create_sheet_with_headers(&self, spreadsheet_id: &str, sheet_title: &str, headers: Vec<String>) -> Result<i32, SheetsError> {
+        let sheet_id = self.add_sheet(spreadsheet_id, sheet_title).await?;
+
+        let range = format!("{}!A1:{}", quote_sheet(sheet_title), column_letter(headers.len()));
+        self.update_values(spreadsheet_id, &range, vec![headers]).await?;
+        Ok(sheet_id)
+    }
+}
+
+fn add_sheet_request(sheet_title: &str) -> Request {
+    Request { add_sheet: Some(AddSheetRequest { properties: Some(SheetProperties { title: Some(sheet_title.to_string()), ..Default::default() }) }), ..Default::default() }
+}
+
+// 열 번호(1부터)를 알파벳으로 변환 (1 → A, 27 → AA)
+pub fn column_letter(column_number: usize) -> String {
+    let mut column = String::new();
+    let mut n = column_number;
+
+    while n > 0 {
+        n -= 1;
+        let c = ((n % 26) as u8 + b'A') as char;
+        column.insert(0, c);
+        n /= 26;
+    }
+
+    column
+}
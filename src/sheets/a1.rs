@@ -0,0 +1,79 @@
+use super::SheetsError;
+
+// A1 범위 ("시트!A1:B2", "시트!A:C", "시트!A1", "'시트 이름'!A1", "시트")
+// 행/열은 0 부터 세고 끝은 포함하지 않는다 (None 이면 끝까지)
+pub struct A1Range {
+    pub sheet: Option<String>,
+    pub start_row: usize,
+    pub start_col: usize,
+    pub end_row: Option<usize>,
+    pub end_col: Option<usize>,
+    // "A1" 처럼 셀 하나만 적었는지
+    pub single_cell: bool,
+}
+
+impl A1Range {
+    pub fn parse(range: &str) -> Result<Self, SheetsError> {
+        let invalid = || SheetsError::BadRequest(format!("범위를 해석할 수 없습니다: {}", range));
+
+        let (sheet, cells) = match range.rsplit_once('!') {
+            Some((sheet, cells)) => (Some(unquote_sheet(sheet).ok_or_else(invalid)?), cells),
+            // "시트" 만 적으면 시트 전체, "A1:B2" 만 적으면 첫 시트
+            None if parse_cell(range.split(':').next().unwrap_or_default()).is_none() => {
+                return Ok(A1Range { sheet: Some(unquote_sheet(range).ok_or_else(invalid)?), start_row: 0, start_col: 0, end_row: None, end_col: None, single_cell: false })
+            }
+            None => (None, range),
+        };
+
+        match cells.split_once(':') {
+            Some((start, end)) => {
+                let (start_col, start_row) = parse_cell(start).ok_or_else(invalid)?;
+                let (end_col, end_row) = parse_cell(end).ok_or_else(invalid)?;
+                Ok(A1Range { sheet, start_row: start_row.unwrap_or(0), start_col: start_col.unwrap_or(0), end_row: end_row.map(|row| row + 1), end_col: end_col.map(|col| col + 1), single_cell: false })
+            }
+            None => match parse_cell(cells).ok_or_else(invalid)? {
+                (Some(col), Some(row)) => Ok(A1Range { sheet, start_row: row, start_col: col, end_row: Some(row + 1), end_col: Some(col + 1), single_cell: true }),
+                _ => Err(invalid()),
+            },
+        }
+    }
+}
+
+// 범위에 쓸 시트 이름 (It's → 'It''s', 글자/숫자/_ 만 있는 영문 이름은 그대로)
+pub fn quote_sheet(sheet_title: &str) -> String {
+    if !sheet_title.is_empty() && sheet_title.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        sheet_title.to_string()
+    } else {
+        format!("'{}'", sheet_title.replace('\'', "''"))
+    }
+}
+
+// 'It''s' → It's
+// 따옴표 없이 쓸 수 있는 이름은 글자, 숫자, _ 뿐 (공백이나 기호가 있으면 None)
+fn unquote_sheet(sheet: &str) -> Option<String> {
+    match sheet.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        Some(quoted) => Some(quoted.replace("''", "'")),
+        None if sheet.chars().all(|c| c.is_alphanumeric() || c == '_') => Some(sheet.to_string()),
+        None => None,
+    }
+}
+
+// "B3" → (Some(1), Some(2)), "B" → (Some(1), None), "3" → (None, Some(2))
+// 열은 API 와 같이 ZZZ 까지만 (그래서 "Sheet1" 같은 시트 이름은 셀로 보지 않는다)
+fn parse_cell(cell: &str) -> Option<(Option<usize>, Option<usize>)> {
+    let letters = cell.chars().take_while(|c| c.is_ascii_alphabetic()).count();
+    let (col, row) = cell.split_at(letters);
+    if (col.is_empty() && row.is_empty()) || col.len() > 3 {
+        return None;
+    }
+
+    let col = match col {
+        "" => None,
+        col => Some(col.to_ascii_uppercase().bytes().fold(0usize, |n, b| n * 26 + (b - b'A' + 1) as usize) - 1),
+    };
+    let row = match row {
+        "" => None,
+        row => Some(row.parse::<usize>().ok().filter(|&row| row > 0)? - 1),
+    };
+    Some((col, row))
+}
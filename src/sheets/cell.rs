@@ -0,0 +1,163 @@
+use chrono::{Days, NaiveDate};
+use std::str::FromStr;
+
+// 셀 값 변환 (SheetRow derive 와 read_rows 가 같이 쓴다)
+// 시트는 보이는 값(FORMATTED_VALUE) 을 문자열로 주므로 필드 타입에 맞게 바꾼다
+// 에러는 셀 에러 메시지 (CellError.message)
+
+// 필드 값 → 셀
+pub trait ToCell {
+    fn to_cell(&self) -> String;
+}
+
+// 셀 → 필드 값
+pub trait FromCell: Sized {
+    fn from_cell(value: &str) -> Result<Self, String>;
+
+    // 헤더에 열이 아예 없을 때 쓸 값 (Option 은 None, 나머지는 없음 → 에러)
+    fn missing_column() -> Option<Self> {
+        None
+    }
+}
+
+// #[sheet(format = "...")] 를 쓸 수 있는 타입 (날짜, chrono 형식 문자열)
+pub trait FormatCell: FromCell {
+    fn format_cell(&self, format: &str) -> String;
+    fn parse_cell(value: &str, format: &str) -> Result<Self, String>;
+}
+
+impl ToCell for String {
+    fn to_cell(&self) -> String {
+        self.clone()
+    }
+}
+
+impl FromCell for String {
+    fn from_cell(value: &str) -> Result<Self, String> {
+        Ok(value.to_string())
+    }
+}
+
+macro_rules! number_cell {
+    ($($t:ty),*) => {
+        $(
+            impl ToCell for $t {
+                fn to_cell(&self) -> String {
+                    self.to_string()
+                }
+            }
+
+            impl FromCell for $t {
+                fn from_cell(value: &str) -> Result<Self, String> {
+                    parse_number(value)
+                }
+            }
+        )*
+    };
+}
+
+number_cell!(i8, i16, i32, i64, u8, u16, u32, u64, usize, f32, f64);
+
+// 시트의 체크박스 값과 같은 TRUE/FALSE 로 쓴다
+impl ToCell for bool {
+    fn to_cell(&self) -> String {
+        if *self { "TRUE" } else { "FALSE" }.to_string()
+    }
+}
+
+impl FromCell for bool {
+    fn from_cell(value: &str) -> Result<Self, String> {
+        parse_bool(required(value)?).ok_or_else(|| "참/거짓 값이 아닙니다 (TRUE/FALSE)".to_string())
+    }
+}
+
+impl ToCell for NaiveDate {
+    fn to_cell(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl FromCell for NaiveDate {
+    fn from_cell(value: &str) -> Result<Self, String> {
+        parse_date(required(value)?).ok_or_else(|| "날짜가 아닙니다 (예: 2024-01-01)".to_string())
+    }
+}
+
+impl FormatCell for NaiveDate {
+    fn format_cell(&self, format: &str) -> String {
+        self.format(format).to_string()
+    }
+
+    fn parse_cell(value: &str, format: &str) -> Result<Self, String> {
+        match NaiveDate::parse_from_str(value.trim(), format) {
+            Ok(date) => Ok(date),
+            Err(_) => NaiveDate::from_cell(value).map_err(|_| format!("날짜가 아닙니다 (형식: {})", format)),
+        }
+    }
+}
+
+// 빈 셀은 None
+impl<T: ToCell> ToCell for Option<T> {
+    fn to_cell(&self) -> String {
+        self.as_ref().map(ToCell::to_cell).unwrap_or_default()
+    }
+}
+
+impl<T: FromCell> FromCell for Option<T> {
+    fn from_cell(value: &str) -> Result<Self, String> {
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::from_cell(value).map(Some)
+        }
+    }
+
+    fn missing_column() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: FormatCell> FormatCell for Option<T> {
+    fn format_cell(&self, format: &str) -> String {
+        self.as_ref().map(|value| value.format_cell(format)).unwrap_or_default()
+    }
+
+    fn parse_cell(value: &str, format: &str) -> Result<Self, String> {
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::parse_cell(value, format).map(Some)
+        }
+    }
+}
+
+// 숫자/불리언/날짜는 빈 셀이면 에러
+fn required(value: &str) -> Result<&str, String> {
+    match value.trim() {
+        "" => Err("값이 없습니다".to_string()),
+        value => Ok(value),
+    }
+}
+
+// 앞뒤 공백과 천 단위 쉼표는 무시 ("1,200")
+pub fn parse_number<T: FromStr>(value: &str) -> Result<T, String> {
+    required(value)?.replace(',', "").parse().map_err(|_| "숫자가 아닙니다".to_string())
+}
+
+// TRUE/FALSE, yes/no, y/n, 1/0 (대소문자 무시)
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "yes" | "y" | "1" => Some(true),
+        "false" | "no" | "n" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+// 2024-01-01, 2024/01/01, 2024.01.01, 2024. 1. 1. (한국어 로캘), 45292 (1899-12-30 부터 센 날 수)
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim().trim_end_matches('.');
+    if let Ok(serial) = value.parse::<u64>() {
+        return NaiveDate::from_ymd_opt(1899, 12, 30)?.checked_add_days(Days::new(serial));
+    }
+    ["%Y-%m-%d", "%Y/%m/%d", "%Y.%m.%d"].iter().find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
@@ -0,0 +1,110 @@
+use super::{SheetsBackend, SheetsError};
+use async_trait::async_trait;
+use google_sheets4::api::{AppendValuesResponse, BatchUpdateSpreadsheetRequest, ClearValuesRequest, Request, Response, SheetProperties, Spreadsheet, SpreadsheetProperties, ValueRange};
+use google_sheets4::oauth2::{read_service_account_key, ServiceAccountAuthenticator, ServiceAccountKey};
+use google_sheets4::Sheets;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde_json::json;
+
+type Connector = hyper_rustls::HttpsConnector<hyper::client::HttpConnector>;
+
+// 범위에서 그대로 둘 문자 (URL 의 unreserved 문자)
+const RANGE_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+// 인증을 마친 구글 Sheets API 클라이언트
+// 서비스 계정 키는 만들 때 한 번만 읽고 인증한다
+// hub 가 HTTP 연결 풀과 토큰 캐시를 들고 있으므로 같은 클라이언트로 여러 번 호출하면 재인증하지 않는다
+pub struct SheetsClient {
+    hub: Sheets<Connector>,
+}
+
+impl SheetsClient {
+    // GOOGLE_SERVICE_ACCOUNT_KEY 의 서비스 계정 키로 인증
+    pub async fn from_env() -> Result<Self, SheetsError> {
+        let key_path = std::env::var("GOOGLE_SERVICE_ACCOUNT_KEY").map_err(|_| SheetsError::MissingKey)?;
+        SheetsClient::from_key_file(&key_path).await
+    }
+
+    // 서비스 계정 키 파일로 인증
+    // SHEETS_BASE_URL 이 있으면 구글 대신 그 주소로 요청한다 (sheets_standin 으로 종단 간 테스트할 때, http 허용)
+    // 토큰은 키 파일의 token_uri 에서 받으므로 대역 서버를 쓸 때는 tests/fixtures/standin_service_account.json 사용
+    pub async fn from_key_file(key_path: &str) -> Result<Self, SheetsError> {
+        let secret = read_service_account_key(key_path).await.map_err(SheetsError::Auth)?;
+        SheetsClient::from_key(secret).await
+    }
+
+    // 읽어 둔 서비스 계정 키로 인증 (SHEETS_BASE_URL 은 from_key_file 과 같다)
+    pub async fn from_key(secret: ServiceAccountKey) -> Result<Self, SheetsError> {
+        let auth = ServiceAccountAuthenticator::builder(secret).build().await.map_err(SheetsError::Auth)?;
+
+        let base_url = std::env::var("SHEETS_BASE_URL").ok().map(|url| if url.ends_with('/') { url } else { format!("{}/", url) });
+        let connector = hyper_rustls::HttpsConnectorBuilder::new().with_native_roots();
+        let connector = if base_url.is_some() { connector.https_or_http() } else { connector.https_only() };
+
+        let mut hub = Sheets::new(hyper::Client::builder().build(connector.enable_http1().build()), auth);
+        if let Some(base_url) = base_url {
+            hub.base_url(base_url.clone());
+            hub.root_url(base_url);
+        }
+
+        Ok(SheetsClient { hub })
+    }
+}
+
+#[async_trait]
+impl SheetsBackend for SheetsClient {
+    async fn append_values(&self, spreadsheet_id: &str, range: &str, values: Vec<Vec<String>>) -> Result<AppendValuesResponse, SheetsError> {
+        let req = ValueRange { values: Some(to_json_rows(values)), ..Default::default() };
+        let (_, result) = self.hub.spreadsheets().values_append(req, spreadsheet_id, &encode_range(range)).value_input_option("RAW").doit().await?;
+        Ok(result)
+    }
+
+    async fn update_values(&self, spreadsheet_id: &str, range: &str, values: Vec<Vec<String>>) -> Result<(), SheetsError> {
+        let req = ValueRange { values: Some(to_json_rows(values)), ..Default::default() };
+        self.hub.spreadsheets().values_update(req, spreadsheet_id, &encode_range(range)).value_input_option("RAW").doit().await?;
+        Ok(())
+    }
+
+    async fn get_values(&self, spreadsheet_id: &str, range: &str) -> Result<Vec<Vec<String>>, SheetsError> {
+        let (_, values) = self.hub.spreadsheets().values_get(spreadsheet_id, &encode_range(range)).doit().await?;
+
+        let cell = |value: serde_json::Value| match value {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        };
+        Ok(values.values.unwrap_or_default().into_iter().map(|row| row.into_iter().map(cell).collect()).collect())
+    }
+
+    async fn clear_values(&self, spreadsheet_id: &str, range: &str) -> Result<(), SheetsError> {
+        self.hub.spreadsheets().values_clear(ClearValuesRequest::default(), spreadsheet_id, &encode_range(range)).doit().await?;
+        Ok(())
+    }
+
+    async fn create_spreadsheet(&self, title: &str) -> Result<String, SheetsError> {
+        let spreadsheet = Spreadsheet { properties: Some(SpreadsheetProperties { title: Some(title.to_string()), ..Default::default() }), ..Default::default() };
+        let (_, spreadsheet) = self.hub.spreadsheets().create(spreadsheet).doit().await?;
+        spreadsheet.spreadsheet_id.ok_or(SheetsError::MissingField("spreadsheetId"))
+    }
+
+    async fn get_sheets(&self, spreadsheet_id: &str) -> Result<Vec<SheetProperties>, SheetsError> {
+        // 셀 데이터 없이 시트 속성만 받는다
+        let (_, spreadsheet) = self.hub.spreadsheets().get(spreadsheet_id).param("fields", "sheets.properties").doit().await?;
+        Ok(spreadsheet.sheets.unwrap_or_default().into_iter().filter_map(|sheet| sheet.properties).collect())
+    }
+
+    async fn batch_update(&self, spreadsheet_id: &str, requests: Vec<Request>) -> Result<Vec<Response>, SheetsError> {
+        let request = BatchUpdateSpreadsheetRequest { requests: Some(requests), ..Default::default() };
+        let (_, response) = self.hub.spreadsheets().batch_update(request, spreadsheet_id).doit().await?;
+        Ok(response.replies.unwrap_or_default())
+    }
+}
+
+// hub 는 범위를 URL 경로에 인코딩하지 않고 넣으므로 미리 퍼센트 인코딩한다 (% 가 들어간 시트 이름 등)
+fn encode_range(range: &str) -> String {
+    utf8_percent_encode(range, RANGE_ENCODE_SET).to_string()
+}
+
+// String -> serde_json::Value 변환
+fn to_json_rows(values: Vec<Vec<String>>) -> Vec<Vec<serde_json::Value>> {
+    values.into_iter().map(|row| row.into_iter().map(|cell| json!(cell)).collect()).collect()
+}
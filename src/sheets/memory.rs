@@ -0,0 +1,270 @@
+use super::a1::{quote_sheet, A1Range};
+use super::{column_letter, SheetsBackend, SheetsError};
+use async_trait::async_trait;
+use google_sheets4::api::{AddSheetResponse, AppendValuesResponse, GridRange, Request, Response, SheetProperties, UpdateValuesResponse};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+// 메모리 스프레드시트 (자격 증명/네트워크 없이 테스트할 때 쓰는 SheetsBackend)
+// - 새 스프레드시트에는 Sheet1 (시트 ID 0) 이 있다
+// - 시트마다 셀 격자를 들고 A1 범위로 읽고 쓴다
+// - append 는 범위 안 표의 마지막 행 아래에 쓴다
+// - batch_update 는 AddSheet, SetBasicFilter 만 적용하고 모든 요청을 기록해 둔다
+// 복제한 값은 같은 스프레드시트들을 공유한다 (테스트에서 백엔드를 넘긴 뒤에도 내용을 볼 수 있도록)
+#[derive(Clone, Default)]
+pub struct InMemorySheets {
+    spreadsheets: Arc<Mutex<BTreeMap<String, FakeSpreadsheet>>>,
+}
+
+#[derive(Clone)]
+struct FakeSpreadsheet {
+    sheets: Vec<FakeSheet>,
+    next_sheet_id: i32,
+    requests: Vec<Request>,
+}
+
+#[derive(Clone)]
+struct FakeSheet {
+    sheet_id: i32,
+    title: String,
+    cells: Vec<Vec<String>>,
+    basic_filter: Option<GridRange>,
+}
+
+impl FakeSpreadsheet {
+    fn new() -> Self {
+        let sheet1 = FakeSheet { sheet_id: 0, title: "Sheet1".to_string(), cells: Vec::new(), basic_filter: None };
+        FakeSpreadsheet { sheets: vec![sheet1], next_sheet_id: 1, requests: Vec::new() }
+    }
+
+    // 범위의 시트 (시트 이름이 없으면 첫 시트)
+    fn sheet_mut(&mut self, range: &A1Range) -> Result<&mut FakeSheet, SheetsError> {
+        match &range.sheet {
+            Some(title) => self.sheets.iter_mut().find(|sheet| &sheet.title == title).ok_or_else(|| SheetsError::BadRequest(format!("시트를 찾을 수 없습니다: {}", title))),
+            None => self.sheets.first_mut().ok_or_else(|| SheetsError::BadRequest("시트가 없습니다".to_string())),
+        }
+    }
+
+    // 요청 하나 적용
+    fn apply(&mut self, request: &Request) -> Result<Response, SheetsError> {
+        if let Some(add_sheet) = &request.add_sheet {
+            let properties = add_sheet.properties.clone().unwrap_or_default();
+            let sheet_id = properties.sheet_id.unwrap_or(self.next_sheet_id);
+            let title = properties.title.clone().unwrap_or_else(|| format!("Sheet{}", self.sheets.len() + 1));
+            if self.sheets.iter().any(|sheet| sheet.title == title || sheet.sheet_id == sheet_id) {
+                return Err(SheetsError::BadRequest(format!("이미 있는 시트입니다: {}", title)));
+            }
+
+            self.next_sheet_id = self.next_sheet_id.max(sheet_id + 1);
+            self.sheets.push(FakeSheet { sheet_id, title: title.clone(), cells: Vec::new(), basic_filter: None });
+
+            let index = self.sheets.len() as i32 - 1;
+            let properties = SheetProperties { sheet_id: Some(sheet_id), title: Some(title), index: Some(index), ..properties };
+            return Ok(Response { add_sheet: Some(AddSheetResponse { properties: Some(properties) }), ..Default::default() });
+        }
+
+        if let Some(set_basic_filter) = &request.set_basic_filter {
+            let range = set_basic_filter.filter.as_ref().and_then(|filter| filter.range.clone()).unwrap_or_default();
+            // API 와 마찬가지로 sheet_id 가 없으면 시트 ID 0
+            let sheet_id = range.sheet_id.unwrap_or(0);
+            let sheet = self.sheets.iter_mut().find(|sheet| sheet.sheet_id == sheet_id).ok_or_else(|| SheetsError::BadRequest(format!("시트 ID {} 가 없습니다", sheet_id)))?;
+            sheet.basic_filter = Some(range);
+        }
+
+        Ok(Response::default())
+    }
+}
+
+impl FakeSheet {
+    // (row, col) 셀 쓰기 (격자가 모자라면 늘린다)
+    fn set(&mut self, row: usize, col: usize, value: String) {
+        if self.cells.len() <= row {
+            self.cells.resize(row + 1, Vec::new());
+        }
+        let cells = &mut self.cells[row];
+        if cells.len() <= col {
+            cells.resize(col + 1, String::new());
+        }
+        cells[col] = value;
+    }
+
+    // row 행의 [start_col, end_col) 열에 값이 있는지
+    fn has_values(&self, row: usize, start_col: usize, end_col: usize) -> bool {
+        self.cells[row].iter().take(end_col).skip(start_col).any(|cell| !cell.is_empty())
+    }
+}
+
+impl InMemorySheets {
+    pub fn new() -> Self {
+        InMemorySheets::default()
+    }
+
+    // 시트 이름 (시트 순서대로)
+    pub fn sheet_titles(&self, spreadsheet_id: &str) -> Vec<String> {
+        let spreadsheets = self.spreadsheets.lock().unwrap();
+        spreadsheets.get(spreadsheet_id).map(|spreadsheet| spreadsheet.sheets.iter().map(|sheet| sheet.title.clone()).collect()).unwrap_or_default()
+    }
+
+    // 시트의 모든 행 (끝의 빈 셀/행은 빠진다)
+    pub fn sheet_rows(&self, spreadsheet_id: &str, sheet_title: &str) -> Option<Vec<Vec<String>>> {
+        let spreadsheets = self.spreadsheets.lock().unwrap();
+        let sheet = spreadsheets.get(spreadsheet_id)?.sheets.iter().find(|sheet| sheet.title == sheet_title)?;
+        Some(trim_rows(sheet.cells.clone()))
+    }
+
+    // 시트의 기본 필터 범위
+    pub fn basic_filter(&self, spreadsheet_id: &str, sheet_title: &str) -> Option<GridRange> {
+        let spreadsheets = self.spreadsheets.lock().unwrap();
+        spreadsheets.get(spreadsheet_id)?.sheets.iter().find(|sheet| sheet.title == sheet_title)?.basic_filter.clone()
+    }
+
+    // 지금까지 batch_update 로 받은 요청 (받은 순서대로)
+    pub fn requests(&self, spreadsheet_id: &str) -> Vec<Request> {
+        let spreadsheets = self.spreadsheets.lock().unwrap();
+        spreadsheets.get(spreadsheet_id).map(|spreadsheet| spreadsheet.requests.clone()).unwrap_or_default()
+    }
+
+    // 스프레드시트 하나를 잠그고 f 실행
+    fn with_spreadsheet<T>(&self, spreadsheet_id: &str, f: impl FnOnce(&mut FakeSpreadsheet) -> Result<T, SheetsError>) -> Result<T, SheetsError> {
+        let mut spreadsheets = self.spreadsheets.lock().unwrap();
+        let spreadsheet = spreadsheets.get_mut(spreadsheet_id).ok_or_else(|| SheetsError::NotFound(spreadsheet_id.to_string()))?;
+        f(spreadsheet)
+    }
+}
+
+#[async_trait]
+impl SheetsBackend for InMemorySheets {
+    async fn append_values(&self, spreadsheet_id: &str, range: &str, values: Vec<Vec<String>>) -> Result<AppendValuesResponse, SheetsError> {
+        let range = A1Range::parse(range)?;
+        self.with_spreadsheet(spreadsheet_id, |spreadsheet| {
+            let sheet = spreadsheet.sheet_mut(&range)?;
+
+            // 범위 안 열에서 값이 있는 마지막 행 바로 아래부터 쓴다 (범위의 행 끝은 보지 않는다)
+            let end_col = range.end_col.unwrap_or(usize::MAX);
+            let last_row = (range.start_row..sheet.cells.len()).rev().find(|&row| sheet.has_values(row, range.start_col, end_col));
+            let table_range = last_row.map(|last_row| {
+                let table_width = sheet.cells[range.start_row..=last_row].iter().map(Vec::len).max().unwrap_or(0).min(end_col);
+                format_a1(&sheet.title, range.start_row, range.start_col, last_row + 1, table_width)
+            });
+            let start_row = last_row.map_or(range.start_row, |row| row + 1);
+
+            let width = values.iter().map(Vec::len).max().unwrap_or(0);
+            let height = values.len();
+            for (i, row) in values.into_iter().enumerate() {
+                for (j, value) in row.into_iter().enumerate() {
+                    sheet.set(start_row + i, range.start_col + j, value);
+                }
+            }
+
+            let updates = UpdateValuesResponse {
+                spreadsheet_id: Some(spreadsheet_id.to_string()),
+                updated_range: Some(format_a1(&sheet.title, start_row, range.start_col, start_row + height, range.start_col + width)),
+                updated_rows: Some(height as i32),
+                updated_columns: Some(width as i32),
+                updated_cells: Some((height * width) as i32),
+                ..Default::default()
+            };
+            Ok(AppendValuesResponse { spreadsheet_id: Some(spreadsheet_id.to_string()), table_range, updates: Some(updates) })
+        })
+    }
+
+    async fn update_values(&self, spreadsheet_id: &str, range: &str, values: Vec<Vec<String>>) -> Result<(), SheetsError> {
+        let a1 = A1Range::parse(range)?;
+        self.with_spreadsheet(spreadsheet_id, |spreadsheet| {
+            // 셀 하나만 주면 그 셀부터 값 크기만큼, 범위를 주면 범위를 넘을 수 없다 (API 와 같음)
+            if !a1.single_cell {
+                let too_tall = a1.end_row.is_some_and(|end_row| a1.start_row + values.len() > end_row);
+                let too_wide = a1.end_col.is_some_and(|end_col| values.iter().any(|row| a1.start_col + row.len() > end_col));
+                if too_tall || too_wide {
+                    return Err(SheetsError::BadRequest(format!("값이 범위 {} 를 넘습니다", range)));
+                }
+            }
+
+            let sheet = spreadsheet.sheet_mut(&a1)?;
+            for (i, row) in values.into_iter().enumerate() {
+                for (j, value) in row.into_iter().enumerate() {
+                    sheet.set(a1.start_row + i, a1.start_col + j, value);
+                }
+            }
+            Ok(())
+        })
+    }
+
+    async fn get_values(&self, spreadsheet_id: &str, range: &str) -> Result<Vec<Vec<String>>, SheetsError> {
+        let range = A1Range::parse(range)?;
+        self.with_spreadsheet(spreadsheet_id, |spreadsheet| {
+            let sheet = spreadsheet.sheet_mut(&range)?;
+            let end_row = range.end_row.unwrap_or(usize::MAX).min(sheet.cells.len());
+
+            let rows = (range.start_row..end_row.max(range.start_row))
+                .map(|row| {
+                    let cells = &sheet.cells[row];
+                    let end_col = range.end_col.unwrap_or(usize::MAX).min(cells.len());
+                    cells.get(range.start_col..end_col.max(range.start_col)).unwrap_or_default().to_vec()
+                })
+                .collect();
+            Ok(trim_rows(rows))
+        })
+    }
+
+    async fn clear_values(&self, spreadsheet_id: &str, range: &str) -> Result<(), SheetsError> {
+        let range = A1Range::parse(range)?;
+        self.with_spreadsheet(spreadsheet_id, |spreadsheet| {
+            let sheet = spreadsheet.sheet_mut(&range)?;
+            let end_row = range.end_row.unwrap_or(usize::MAX).min(sheet.cells.len());
+
+            for row in range.start_row..end_row.max(range.start_row) {
+                let cells = &mut sheet.cells[row];
+                let end_col = range.end_col.unwrap_or(usize::MAX).min(cells.len());
+                for cell in cells.iter_mut().take(end_col).skip(range.start_col) {
+                    cell.clear();
+                }
+            }
+            Ok(())
+        })
+    }
+
+    async fn create_spreadsheet(&self, _title: &str) -> Result<String, SheetsError> {
+        let mut spreadsheets = self.spreadsheets.lock().unwrap();
+        let spreadsheet_id = format!("spreadsheet-{}", spreadsheets.len() + 1);
+        spreadsheets.insert(spreadsheet_id.clone(), FakeSpreadsheet::new());
+        Ok(spreadsheet_id)
+    }
+
+    async fn get_sheets(&self, spreadsheet_id: &str) -> Result<Vec<SheetProperties>, SheetsError> {
+        self.with_spreadsheet(spreadsheet_id, |spreadsheet| {
+            let properties = spreadsheet.sheets.iter().enumerate().map(|(index, sheet)| SheetProperties { sheet_id: Some(sheet.sheet_id), title: Some(sheet.title.clone()), index: Some(index as i32), ..Default::default() });
+            Ok(properties.collect())
+        })
+    }
+
+    async fn batch_update(&self, spreadsheet_id: &str, requests: Vec<Request>) -> Result<Vec<Response>, SheetsError> {
+        self.with_spreadsheet(spreadsheet_id, |spreadsheet| {
+            // 사본에 적용하고 모두 성공했을 때만 반영한다
+            let mut updated = spreadsheet.clone();
+            let replies = requests.iter().map(|request| updated.apply(request)).collect::<Result<Vec<_>, _>>()?;
+
+            updated.requests.extend(requests);
+            *spreadsheet = updated;
+            Ok(replies)
+        })
+    }
+}
+
+// 끝의 빈 셀과 빈 행을 뺀다 (API 응답과 같은 모양)
+fn trim_rows(mut rows: Vec<Vec<String>>) -> Vec<Vec<String>> {
+    for row in rows.iter_mut() {
+        while row.last().is_some_and(String::is_empty) {
+            row.pop();
+        }
+    }
+    while rows.last().is_some_and(Vec::is_empty) {
+        rows.pop();
+    }
+    rows
+}
+
+// 0 부터 센 [start, end) 범위를 A1 표기로
+fn format_a1(sheet_title: &str, start_row: usize, start_col: usize, end_row: usize, end_col: usize) -> String {
+    format!("{}!{}{}:{}{}", quote_sheet(sheet_title), column_letter(start_col + 1), start_row + 1, column_letter(end_col.max(start_col + 1)), end_row.max(start_row + 1))
+}
@@ -0,0 +1,71 @@
+use super::{provision_workbook, quote_sheet, read_sheet_rows, SheetRow, SheetsBackend, SheetsError, WorkbookSchema};
+use chrono::NaiveDate;
+
+// 프로젝트 관리 시트 (이름 접두사, 헤더) - 기본 워크북 스키마(schemas/project_workbook.toml) 와 같은 구조
+// 헤더는 아래 행 구조체의 #[derive(SheetRow)] 에서 나온다
+// 스키마 파일의 열 이름과 어긋나면 tests/sheets.rs 의 project_schema_matches_row_structs 가 실패한다
+pub const PROJECT_SHEETS: [(&str, &[&str]); 3] = [("Project_Overview", ProjectOverview::HEADERS), ("Task_Management", Task::HEADERS), ("Roles_Responsibilities", RoleResponsibility::HEADERS)];
+
+// Project_Overview 시트의 행
+#[derive(Debug, Clone, PartialEq, SheetRow)]
+#[sheet_row(crate = "crate")]
+pub struct ProjectOverview {
+    #[sheet(rename = "Project_ID")]
+    pub project_id: String,
+    pub project_name: String,
+    pub project_manager: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub project_goal: Option<String>,
+    pub budget: Option<f64>,
+    pub key_stakeholders: Option<String>,
+    pub status: Option<String>,
+    pub description: Option<String>,
+}
+
+// Task_Management 시트의 행
+#[derive(Debug, Clone, PartialEq, SheetRow)]
+#[sheet_row(crate = "crate")]
+pub struct Task {
+    #[sheet(rename = "Project_ID")]
+    pub project_id: String,
+    #[sheet(rename = "Task_ID")]
+    pub task_id: String,
+    #[sheet(rename = "Parent_Task_ID")]
+    pub parent_task_id: Option<String>,
+    pub task_name: String,
+    pub description: Option<String>,
+    pub priority: Option<String>,
+    pub assignee: Option<String>,
+    pub accountable: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub status: Option<String>,
+}
+
+// Roles_Responsibilities 시트의 행
+#[derive(Debug, Clone, PartialEq, SheetRow)]
+#[sheet_row(crate = "crate")]
+pub struct RoleResponsibility {
+    pub name: String,
+    pub role: Option<String>,
+    pub department: Option<String>,
+    pub skills: Option<String>,
+    pub projects: Option<String>,
+    pub availability: Option<String>,
+    pub email: Option<String>,
+    pub slack: Option<String>,
+    pub notes: Option<String>,
+}
+
+// 기본 워크북 스키마로 프로젝트 시트를 "{접두사}_{프로젝트 이름}" 으로 만들고 새로 만든 시트 이름을 돌려준다
+// 이미 있는 시트는 빠진 열만 채운다
+pub async fn create_project_sheets<B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, project_name: &str) -> Result<Vec<String>, SheetsError> {
+    let plan = provision_workbook(sheets, spreadsheet_id, &WorkbookSchema::project(), project_name).await?;
+    Ok(plan.created_sheets())
+}
+
+// Task_Management_{프로젝트 이름} 시트의 작업 목록
+pub async fn read_tasks<B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, project_name: &str) -> Result<Vec<Task>, SheetsError> {
+    read_sheet_rows(sheets, spreadsheet_id, &quote_sheet(&format!("Task_Management_{}", project_name))).await
+}
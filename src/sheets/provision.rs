@@ -0,0 +1,303 @@
+use super::schema::{parse_color, ColumnSchema, ColumnType, SheetSchema, WorkbookSchema};
+use super::{column_letter, quote_sheet, SheetsBackend, SheetsError};
+use google_sheets4::api::{
+    AddBandingRequest, AddConditionalFormatRuleRequest, BandedRange, BandingProperties, BasicFilter, BooleanCondition, BooleanRule, CellData, CellFormat, Color, ConditionValue, ConditionalFormatRule, DataValidationRule, DimensionProperties,
+    DimensionRange, GridProperties, GridRange, NumberFormat, RepeatCellRequest, Request, SetBasicFilterRequest, SetDataValidationRequest, SheetProperties, TextFormat, UpdateDimensionPropertiesRequest, UpdateSheetPropertiesRequest,
+};
+use google_sheets4::FieldMask;
+use std::fmt;
+
+// 스키마대로 워크북 맞추기 (여러 번 실행해도 된다)
+// 1. plan_workbook: 지금 스프레드시트와 스키마를 비교해 바꿀 것을 정한다
+//    - 없는 시트는 새로 만든다 (헤더 + 고정 행, 필터, 열 타입별 검증/형식, 헤더 서식, 줄무늬, 조건부 서식)
+//    - 있는 시트는 헤더 끝에 빠진 열만 추가한다
+//    - 이름이 바뀐 것 같은 열(같은 위치에서 이름만 다름)과 스키마에 없는 열은 알리기만 하고 건드리지 않는다
+// 2. apply_plan: 계획대로 적용 (spreadsheet_create --plan 이면 계획만 보여 준다)
+
+// 워크북 계획 (스키마의 시트 순서대로)
+#[derive(Debug, Clone)]
+pub struct WorkbookPlan {
+    pub sheets: Vec<SheetPlan>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SheetPlan {
+    pub title: String,
+    pub schema: SheetSchema,
+    // 이미 있는 시트면 시트 ID 와 지금 헤더
+    pub sheet_id: Option<i32>,
+    pub existing_headers: Vec<String>,
+    // 헤더 끝에 추가할 열 (새 시트면 모든 열)
+    pub added_columns: Vec<String>,
+    // (지금 이름, 스키마 이름)
+    pub renamed_columns: Vec<(String, String)>,
+    // 스키마에 없는 열
+    pub removed_columns: Vec<String>,
+}
+
+impl WorkbookPlan {
+    // 적용할 것이 없는지 (알리기만 하는 열은 빼고)
+    pub fn is_empty(&self) -> bool {
+        self.sheets.iter().all(|sheet| sheet.sheet_id.is_some() && sheet.added_columns.is_empty())
+    }
+
+    // 새로 만들 시트 이름
+    pub fn created_sheets(&self) -> Vec<String> {
+        self.sheets.iter().filter(|sheet| sheet.sheet_id.is_none()).map(|sheet| sheet.title.clone()).collect()
+    }
+}
+
+// + 적용할 것, ! 알리기만 하는 것
+impl fmt::Display for WorkbookPlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for sheet in &self.sheets {
+            if sheet.sheet_id.is_none() {
+                writeln!(f, "+ 시트 만들기 {} ({}개 열)", sheet.title, sheet.added_columns.len())?;
+                continue;
+            }
+            if !sheet.added_columns.is_empty() {
+                writeln!(f, "+ {} 에 열 추가: {}", sheet.title, sheet.added_columns.join(", "))?;
+            }
+            for (from, to) in &sheet.renamed_columns {
+                writeln!(f, "! {} 의 열 {} 은 스키마의 {} 로 이름이 바뀐 것 같습니다 (바꾸지 않음)", sheet.title, from, to)?;
+            }
+            for column in &sheet.removed_columns {
+                writeln!(f, "! {} 의 열 {} 은 스키마에 없습니다 (지우지 않음)", sheet.title, column)?;
+            }
+        }
+        if self.is_empty() {
+            writeln!(f, "= 바꿀 것이 없습니다")?;
+        }
+        Ok(())
+    }
+}
+
+// 지금 스프레드시트와 스키마 비교
+pub async fn plan_workbook<B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, schema: &WorkbookSchema, project_name: &str) -> Result<WorkbookPlan, SheetsError> {
+    let existing = sheets.get_sheets(spreadsheet_id).await?;
+    let mut plans = Vec::new();
+
+    for sheet in &schema.sheets {
+        let title = sheet.title(project_name);
+        let sheet_id = existing.iter().find(|properties| properties.title.as_deref() == Some(title.as_str())).and_then(|properties| properties.sheet_id);
+
+        let existing_headers = match sheet_id {
+            Some(_) => sheets.get_values(spreadsheet_id, &format!("{}!1:1", quote_sheet(&title))).await?.into_iter().next().unwrap_or_default(),
+            None => Vec::new(),
+        };
+        plans.push(plan_sheet(title, sheet, sheet_id, existing_headers));
+    }
+
+    Ok(WorkbookPlan { sheets: plans })
+}
+
+fn plan_sheet(title: String, sheet: &SheetSchema, sheet_id: Option<i32>, existing_headers: Vec<String>) -> SheetPlan {
+    let wanted = sheet.headers();
+    let has = |headers: &[String], name: &str| headers.iter().any(|header| header.trim() == name.trim());
+
+    // 같은 위치에서 지금 이름은 스키마에 없고 스키마 이름은 시트에 없으면 이름이 바뀐 열로 본다
+    let renamed_columns: Vec<(String, String)> = existing_headers
+        .iter()
+        .zip(&wanted)
+        .filter(|(current, name)| !current.trim().is_empty() && !has(&wanted, current) && !has(&existing_headers, name))
+        .map(|(current, name)| (current.clone(), name.clone()))
+        .collect();
+
+    let added_columns = wanted.iter().filter(|name| !has(&existing_headers, name) && !renamed_columns.iter().any(|(_, to)| to == *name)).cloned().collect();
+    let removed_columns = existing_headers
+        .iter()
+        .filter(|current| !current.trim().is_empty() && !has(&wanted, current) && !renamed_columns.iter().any(|(from, _)| from == *current))
+        .cloned()
+        .collect();
+
+    SheetPlan { title, schema: sheet.clone(), sheet_id, existing_headers, added_columns, renamed_columns, removed_columns }
+}
+
+// 계획 적용
+pub async fn apply_plan<B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, plan: &WorkbookPlan) -> Result<(), SheetsError> {
+    for sheet in &plan.sheets {
+        let requests = match sheet.sheet_id {
+            None => {
+                let sheet_id = sheets.create_sheet_with_headers(spreadsheet_id, &sheet.title, sheet.schema.headers()).await?;
+                sheet_requests(sheet_id, &sheet.schema)
+            }
+            Some(_) if sheet.added_columns.is_empty() => continue,
+            Some(sheet_id) => {
+                // 지금 헤더 바로 뒤에 쓴다
+                let start = sheet.existing_headers.len();
+                let end = start + sheet.added_columns.len();
+                let range = format!("{}!{}1:{}1", quote_sheet(&sheet.title), column_letter(start + 1), column_letter(end));
+                sheets.update_values(spreadsheet_id, &range, vec![sheet.added_columns.clone()]).await?;
+
+                let mut requests = Vec::new();
+                // 필터 범위를 늘린 헤더까지
+                if sheet.schema.filter {
+                    requests.push(filter_request(sheet_id, end));
+                }
+                for (i, name) in sheet.added_columns.iter().enumerate() {
+                    let column = sheet.schema.columns.iter().find(|column| &column.name == name).expect("스키마의 열");
+                    requests.extend(column_requests(sheet_id, start + i, column));
+                }
+                requests
+            }
+        };
+
+        if !requests.is_empty() {
+            sheets.batch_update(spreadsheet_id, requests).await?;
+        }
+    }
+
+    Ok(())
+}
+
+// 계획을 세우고 바로 적용한 뒤 계획을 돌려준다
+pub async fn provision_workbook<B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, schema: &WorkbookSchema, project_name: &str) -> Result<WorkbookPlan, SheetsError> {
+    let plan = plan_workbook(sheets, spreadsheet_id, schema, project_name).await?;
+    apply_plan(sheets, spreadsheet_id, &plan).await?;
+    Ok(plan)
+}
+
+// 새 시트 설정 요청 (모두 sheet_id 시트에 적용)
+fn sheet_requests(sheet_id: i32, sheet: &SheetSchema) -> Vec<Request> {
+    let mut requests = Vec::new();
+
+    if sheet.frozen_rows > 0 {
+        requests.push(frozen_rows_request(sheet_id, sheet.frozen_rows));
+    }
+
+    if sheet.filter {
+        requests.push(filter_request(sheet_id, sheet.columns.len()));
+    }
+
+    for (i, column) in sheet.columns.iter().enumerate() {
+        requests.extend(column_requests(sheet_id, i, column));
+    }
+
+    // 아래 서식은 열 범위를 정하지 않아 나중에 추가한 열에도 그대로 적용된다
+    // 헤더 행: 굵게 + 배경색
+    let header = GridRange { sheet_id: Some(sheet_id), start_row_index: Some(0), end_row_index: Some(1), ..Default::default() };
+    let header_format = CellFormat { background_color: Some(color(&sheet.header_color)), text_format: Some(TextFormat { bold: Some(true), ..Default::default() }), ..Default::default() };
+    requests.push(Request {
+        repeat_cell: Some(RepeatCellRequest {
+            range: Some(header),
+            cell: Some(CellData { user_entered_format: Some(header_format), ..Default::default() }),
+            fields: Some(FieldMask::new(&["userEnteredFormat.backgroundColor", "userEnteredFormat.textFormat.bold"])),
+        }),
+        ..Default::default()
+    });
+
+    // 데이터 행 줄무늬 (흰색/연한 회색)
+    if sheet.banding {
+        let properties = BandingProperties { first_band_color: Some(color("#FFFFFF")), second_band_color: Some(color("#F3F3F3")), ..Default::default() };
+        let banded_range = BandedRange { range: Some(data_rows(sheet_id)), row_properties: Some(properties), ..Default::default() };
+        requests.push(Request { add_banding: Some(AddBandingRequest { banded_range: Some(banded_range) }), ..Default::default() });
+    }
+
+    // 조건이 참인 행 전체를 칠한다 (스키마 순서대로 앞 규칙이 우선)
+    for (i, highlight) in sheet.highlights.iter().enumerate() {
+        let formula = sheet.highlight_formula(&highlight.when).expect("검증된 스키마");
+        let rule = ConditionalFormatRule {
+            ranges: Some(vec![data_rows(sheet_id)]),
+            boolean_rule: Some(BooleanRule {
+                condition: Some(BooleanCondition { type_: Some("CUSTOM_FORMULA".to_string()), values: Some(vec![ConditionValue { user_entered_value: Some(formula), ..Default::default() }]) }),
+                format: Some(CellFormat { background_color: Some(color(&highlight.color)), ..Default::default() }),
+            }),
+            ..Default::default()
+        };
+        requests.push(Request { add_conditional_format_rule: Some(AddConditionalFormatRuleRequest { rule: Some(rule), index: Some(i as i32) }), ..Default::default() });
+    }
+
+    requests
+}
+
+// 헤더 아래 모든 행 (열 제한 없음)
+fn data_rows(sheet_id: i32) -> GridRange {
+    GridRange { sheet_id: Some(sheet_id), start_row_index: Some(1), ..Default::default() }
+}
+
+// 스키마 검증을 거친 "#RRGGBB"
+fn color(hex: &str) -> Color {
+    let (red, green, blue) = parse_color(hex).expect("검증된 색");
+    Color { red: Some(red), green: Some(green), blue: Some(blue), ..Default::default() }
+}
+
+// 위에서 rows 개 행 고정
+pub fn frozen_rows_request(sheet_id: i32, rows: u32) -> Request {
+    Request {
+        update_sheet_properties: Some(UpdateSheetPropertiesRequest {
+            properties: Some(SheetProperties { sheet_id: Some(sheet_id), grid_properties: Some(GridProperties { frozen_row_count: Some(rows as i32), ..Default::default() }), ..Default::default() }),
+            fields: Some(FieldMask::new(&["gridProperties.frozenRowCount"])),
+        }),
+        ..Default::default()
+    }
+}
+
+// 헤더 행부터 columns 개 열에 기본 필터
+pub fn filter_request(sheet_id: i32, columns: usize) -> Request {
+    let range = GridRange { sheet_id: Some(sheet_id), start_row_index: Some(0), start_column_index: Some(0), end_column_index: Some(columns as i32), ..Default::default() };
+    Request { set_basic_filter: Some(SetBasicFilterRequest { filter: Some(BasicFilter { range: Some(range), ..Default::default() }) }), ..Default::default() }
+}
+
+// 열 하나의 설정 요청 (index 는 0 부터 센 열 위치)
+// 드롭다운/타입 검증, 표시 형식, 열 너비
+fn column_requests(sheet_id: i32, index: usize, column: &ColumnSchema) -> Vec<Request> {
+    let mut requests = Vec::new();
+
+    // 헤더 바로 아래 셀 기준 (수식 검증은 열을 따라 상대 참조된다)
+    let first_cell = format!("{}2", column_letter(index + 1));
+    let validation = match column.kind {
+        ColumnType::Text if !column.values.is_empty() => Some(("ONE_OF_LIST", column.values.clone(), "목록에서 고르세요")),
+        ColumnType::Text => None,
+        ColumnType::Number | ColumnType::Currency => Some(("CUSTOM_FORMULA", vec![format!("=ISNUMBER({})", first_cell)], "숫자를 입력하세요")),
+        ColumnType::Date => Some(("DATE_IS_VALID", Vec::new(), "날짜를 입력하세요 (예: 2024-01-31)")),
+        ColumnType::Boolean => Some(("BOOLEAN", Vec::new(), "")),
+    };
+    if let Some((condition_type, values, input_message)) = validation {
+        let values = (!values.is_empty()).then(|| values.into_iter().map(|value| ConditionValue { user_entered_value: Some(value), ..Default::default() }).collect());
+        let rule = DataValidationRule {
+            condition: Some(BooleanCondition { type_: Some(condition_type.to_string()), values }),
+            input_message: (!input_message.is_empty()).then(|| input_message.to_string()),
+            strict: Some(true),
+            show_custom_ui: Some(true),
+        };
+        requests.push(Request { set_data_validation: Some(SetDataValidationRequest { range: Some(column_range(sheet_id, index)), rule: Some(rule) }), ..Default::default() });
+    }
+
+    // (시트 숫자 형식 종류, 기본 패턴)
+    let number_format = match column.kind {
+        ColumnType::Number => column.format.as_ref().map(|_| ("NUMBER", None)),
+        ColumnType::Currency => Some(("CURRENCY", None)),
+        ColumnType::Date => Some(("DATE", Some("yyyy-mm-dd"))),
+        ColumnType::Text | ColumnType::Boolean => None,
+    };
+    if let Some((format_type, default_pattern)) = number_format {
+        let number_format = NumberFormat { type_: Some(format_type.to_string()), pattern: column.format.clone().or(default_pattern.map(str::to_string)) };
+        requests.push(Request {
+            repeat_cell: Some(RepeatCellRequest {
+                range: Some(column_range(sheet_id, index)),
+                cell: Some(CellData { user_entered_format: Some(CellFormat { number_format: Some(number_format), ..Default::default() }), ..Default::default() }),
+                fields: Some(FieldMask::new(&["userEnteredFormat.numberFormat"])),
+            }),
+            ..Default::default()
+        });
+    }
+
+    if let Some(width) = column.width {
+        requests.push(Request {
+            update_dimension_properties: Some(UpdateDimensionPropertiesRequest {
+                range: Some(DimensionRange { sheet_id: Some(sheet_id), dimension: Some("COLUMNS".to_string()), start_index: Some(index as i32), end_index: Some(index as i32 + 1) }),
+                properties: Some(DimensionProperties { pixel_size: Some(width as i32), ..Default::default() }),
+                fields: Some(FieldMask::new(&["pixelSize"])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    requests
+}
+
+// 헤더 아래 index 번째 열 전체
+fn column_range(sheet_id: i32, index: usize) -> GridRange {
+    GridRange { sheet_id: Some(sheet_id), start_row_index: Some(1), start_column_index: Some(index as i32), end_column_index: Some(index as i32 + 1), ..Default::default() }
+}
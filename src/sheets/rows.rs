@@ -0,0 +1,271 @@
+use super::a1::A1Range;
+use super::cell::{parse_number, FromCell};
+use super::{column_letter, SheetsBackend, SheetsError};
+use chrono::NaiveDate;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+// 시트 행을 구조체로 읽기
+// - read_rows: serde Deserialize 구조체
+// - read_sheet_rows / append_sheet_rows: #[derive(SheetRow)] 구조체 (헤더 이름은 derive 속성으로)
+// read_rows 는
+// - 범위의 첫 행이 헤더, 헤더와 필드 이름은 대소문자/밑줄/공백을 무시하고 맞춘다 (Task_ID ↔ task_id)
+// - 숫자, 불리언은 cell.rs 의 FromCell 과 같은 규칙으로 바꾼다
+// - 날짜: #[serde(deserialize_with = "date_cell")] 로 (2024-01-01, 2024/01/01, 2024. 1. 1, 시트 날짜 일련번호)
+// - 빈 셀과 헤더에 없는 열은 Option 필드면 None
+// - 완전히 빈 행은 건너뛴다
+
+// #[derive(SheetRow)] 가 구현하는 트레이트
+pub trait SheetRow: Sized {
+    // 헤더 (열 순서대로)
+    const HEADERS: &'static [&'static str];
+
+    // HEADERS 순서대로 셀 값
+    fn to_row(&self) -> Vec<String>;
+
+    // 헤더 행과 한 행의 셀로 값 만들기 (열 위치는 헤더 이름으로 찾는다)
+    fn from_row(headers: &[String], cells: &[String]) -> Result<Self, FieldError>;
+}
+
+// 한 행을 읽다 난 에러 (셀 값에서 났으면 몇 번째 열인지, 0 부터)
+#[derive(Debug)]
+pub struct FieldError {
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl de::Error for FieldError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        FieldError { column: None, message: message.to_string() }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+// 읽을 수 없는 셀
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellError {
+    // 시트의 행 번호 (1부터)
+    pub row: usize,
+    // 셀 위치 ("C5") 와 헤더 - 필수 열이 없을 때처럼 특정 셀의 문제가 아니면 None
+    pub cell: Option<String>,
+    pub header: Option<String>,
+    pub value: String,
+    pub message: String,
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.cell, &self.header) {
+            (Some(cell), Some(header)) => write!(f, "{} ({}) 값 \"{}\": {}", cell, header, self.value, self.message),
+            _ => write!(f, "{}행: {}", self.row, self.message),
+        }
+    }
+}
+
+// range 의 첫 행을 헤더로 삼아 나머지 행을 T 로 읽는다
+// 읽을 수 없는 셀이 있으면 행마다 첫 번째 에러를 모아 SheetsError::InvalidRows 로 돌려준다
+pub async fn read_rows<T: DeserializeOwned, B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, range: &str) -> Result<Vec<T>, SheetsError> {
+    read_with(sheets, spreadsheet_id, range, |headers, cells| T::deserialize(RowDeserializer { headers, cells })).await
+}
+
+// read_rows 와 같지만 #[derive(SheetRow)] 구조체로
+pub async fn read_sheet_rows<T: SheetRow, B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, range: &str) -> Result<Vec<T>, SheetsError> {
+    read_with(sheets, spreadsheet_id, range, T::from_row).await
+}
+
+// range 의 표 아래에 행 추가 (열 순서는 T::HEADERS)
+pub async fn append_sheet_rows<T: SheetRow, B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, range: &str, rows: &[T]) -> Result<(), SheetsError> {
+    sheets.append_values(spreadsheet_id, range, rows.iter().map(SheetRow::to_row).collect()).await?;
+    Ok(())
+}
+
+// SheetRow derive 가 쓰는 함수 - header 열의 셀을 parse 로 바꾼다
+// 열이 아예 없으면 Option 필드는 None, 나머지는 열이 없다는 에러
+pub fn parse_row_cell<T: FromCell>(headers: &[String], cells: &[String], header: &str, parse: impl FnOnce(&str) -> Result<T, String>) -> Result<T, FieldError> {
+    let wanted = normalize(header);
+    match headers.iter().position(|h| normalize(h) == wanted) {
+        Some(column) => parse(cells.get(column).map_or("", String::as_str)).map_err(|message| FieldError { column: Some(column), message }),
+        None => T::missing_column().ok_or_else(|| FieldError { column: None, message: format!("{} 열이 없습니다", header) }),
+    }
+}
+
+async fn read_with<T, B: SheetsBackend + ?Sized>(sheets: &B, spreadsheet_id: &str, range: &str, parse: impl Fn(&[String], &[String]) -> Result<T, FieldError>) -> Result<Vec<T>, SheetsError> {
+    let origin = A1Range::parse(range)?;
+    let values = sheets.get_values(spreadsheet_id, range).await?;
+
+    let mut rows = values.into_iter();
+    let headers = rows.next().unwrap_or_default();
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, cells) in rows.enumerate() {
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+
+        let row = origin.start_row + i + 2;
+        match parse(&headers, &cells) {
+            Ok(item) => items.push(item),
+            Err(e) => errors.push(match e.column {
+                Some(column) => CellError {
+                    row,
+                    cell: Some(format!("{}{}", column_letter(origin.start_col + column + 1), row)),
+                    header: headers.get(column).cloned(),
+                    value: cells.get(column).cloned().unwrap_or_default(),
+                    message: e.message,
+                },
+                None => CellError { row, cell: None, header: None, value: String::new(), message: e.message },
+            }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(items)
+    } else {
+        Err(SheetsError::InvalidRows(errors))
+    }
+}
+
+// 날짜 셀 (#[serde(deserialize_with = "date_cell")])
+pub fn date_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    NaiveDate::from_cell(&value).map_err(de::Error::custom)
+}
+
+// 빈 셀이면 None (#[serde(default, deserialize_with = "optional_date_cell")])
+pub fn optional_date_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<NaiveDate>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    Option::<NaiveDate>::from_cell(value.as_deref().unwrap_or_default()).map_err(de::Error::custom)
+}
+
+// 헤더/필드 이름 비교용 ("Task_ID", "task id", "taskId" → "taskid")
+fn normalize(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+// 행 하나 (헤더 → 셀 맵)
+struct RowDeserializer<'a> {
+    headers: &'a [String],
+    cells: &'a [String],
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer<'_> {
+    type Error = FieldError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        let keys = self.headers.iter().enumerate().filter(|(_, header)| !header.trim().is_empty()).map(|(column, header)| (column, header.as_str())).collect();
+        visitor.visit_map(RowAccess { keys, cells: self.cells, column: 0 })
+    }
+
+    // 헤더를 이름이 맞는 필드로 바꿔 넘긴다 (맞는 필드가 없으면 헤더 그대로, serde(rename) 용)
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, FieldError> {
+        let keys = self
+            .headers
+            .iter()
+            .enumerate()
+            .filter(|(_, header)| !header.trim().is_empty())
+            .map(|(column, header)| (column, fields.iter().copied().find(|field| normalize(field) == normalize(header)).unwrap_or(header.as_str())))
+            .collect();
+        visitor.visit_map(RowAccess { keys, cells: self.cells, column: 0 })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct enum identifier ignored_any
+    }
+}
+
+struct RowAccess<'a> {
+    keys: Vec<(usize, &'a str)>,
+    cells: &'a [String],
+    column: usize,
+}
+
+impl<'de> MapAccess<'de> for RowAccess<'_> {
+    type Error = FieldError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, FieldError> {
+        if self.keys.is_empty() {
+            return Ok(None);
+        }
+        let (column, key) = self.keys.remove(0);
+        self.column = column;
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    // 행 끝의 빈 셀은 응답에서 빠지므로 없는 셀은 빈 값
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, FieldError> {
+        let value = self.cells.get(self.column).map_or("", String::as_str);
+        seed.deserialize(CellDeserializer(value)).map_err(|e| FieldError { column: Some(self.column), ..e })
+    }
+}
+
+// 셀 하나 (문자열을 필드 타입에 맞게 바꾼다)
+struct CellDeserializer<'a>(&'a str);
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+                visitor.$visit(parse_number(self.0).map_err(de::Error::custom)?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for CellDeserializer<'_> {
+    type Error = FieldError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        visitor.visit_bool(bool::from_cell(self.0).map_err(de::Error::custom)?)
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        if self.0.trim().is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, FieldError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // 값 없는 enum 변형 (상태 열 등)
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, FieldError> {
+        visitor.visit_enum(self.0.trim().into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
@@ -0,0 +1,214 @@
+use super::{column_letter, SheetsError};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+// 워크북 스키마 (TOML) - spreadsheet_create 가 이대로 시트를 만든다
+// 팀마다 스키마 파일만 바꿔 프로젝트 템플릿을 정할 수 있다 (기본: schemas/project_workbook.toml)
+//
+//   [[sheets]]
+//   name = "Task_Management_{project}"   # {project} 는 프로젝트 이름으로 바뀐다
+//   frozen_rows = 1                       # 고정할 머리 행 수 (기본 1)
+//   filter = true                         # 헤더에 기본 필터 (기본 true)
+//   header_color = "#D9E2F3"              # 헤더 행 배경색 (글자는 굵게)
+//   banding = true                        # 데이터 행 줄무늬 (기본 true)
+//   columns = [
+//     { name = "Task_ID", width = 80 },      # 열 너비 (픽셀, 없으면 시트 기본값)
+//     { name = "Start_Date", type = "date" },
+//     { name = "Budget", type = "currency", format = "₩#,##0" },
+//     { name = "Status", values = ["Todo", "Done"] },   # 드롭다운 (목록 밖 값은 거부)
+//   ]
+// 열 타입마다 데이터 검증과 표시 형식이 붙는다
+// - text: 없음 (values 가 있으면 드롭다운)
+// - number: 숫자만 / currency: 숫자만 + 통화 형식 / date: 날짜만 + yyyy-mm-dd / boolean: 체크박스
+// - format 으로 표시 형식(시트의 숫자 형식 패턴)을 바꿀 수 있다 (number, currency, date)
+//
+//   [[sheets.highlights]]                 # 조건부 서식: 조건이 참인 행을 color 로 칠한다
+//   when = 'AND({End_Date} <> "", DATEVALUE({End_Date}) < TODAY(), {Status} <> "Done")'
+//   color = "#F4CCCC"                     # 기본 연한 빨강
+// when 은 시트 수식, {열 이름} 은 그 행의 셀로 바뀐다
+// 헤더 서식, 줄무늬, 조건부 서식은 시트를 새로 만들 때만 붙인다
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkbookSchema {
+    pub sheets: Vec<SheetSchema>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SheetSchema {
+    pub name: String,
+    pub columns: Vec<ColumnSchema>,
+    #[serde(default = "default_frozen_rows")]
+    pub frozen_rows: u32,
+    #[serde(default = "default_filter")]
+    pub filter: bool,
+    #[serde(default = "default_header_color")]
+    pub header_color: String,
+    #[serde(default = "default_banding")]
+    pub banding: bool,
+    #[serde(default)]
+    pub highlights: Vec<HighlightSchema>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ColumnSchema {
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub kind: ColumnType,
+    // 드롭다운 목록 (text 열만)
+    #[serde(default)]
+    pub values: Vec<String>,
+    // 표시 형식 패턴 (없으면 타입 기본값)
+    pub format: Option<String>,
+    // 열 너비 (픽셀)
+    pub width: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HighlightSchema {
+    pub when: String,
+    #[serde(default = "default_highlight_color")]
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnType {
+    #[default]
+    Text,
+    Number,
+    Currency,
+    Date,
+    Boolean,
+}
+
+fn default_frozen_rows() -> u32 {
+    1
+}
+
+fn default_filter() -> bool {
+    true
+}
+
+fn default_header_color() -> String {
+    "#D9E2F3".to_string()
+}
+
+fn default_banding() -> bool {
+    true
+}
+
+fn default_highlight_color() -> String {
+    "#F4CCCC".to_string()
+}
+
+// 기본 프로젝트 워크북 (Project_Overview, Task_Management, Roles_Responsibilities)
+const PROJECT_WORKBOOK: &str = include_str!("../../schemas/project_workbook.toml");
+
+impl WorkbookSchema {
+    pub fn project() -> Self {
+        WorkbookSchema::from_toml(PROJECT_WORKBOOK).expect("기본 워크북 스키마")
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, SheetsError> {
+        let schema: WorkbookSchema = toml::from_str(text).map_err(|e| SheetsError::InvalidSchema(e.to_string()))?;
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, SheetsError> {
+        let text = std::fs::read_to_string(path).map_err(|e| SheetsError::InvalidSchema(format!("{}: {}", path, e)))?;
+        WorkbookSchema::from_toml(&text)
+    }
+
+    // 시트/열 이름이 비었거나 겹치면 에러
+    fn validate(&self) -> Result<(), SheetsError> {
+        let invalid = |message: String| Err(SheetsError::InvalidSchema(message));
+        if self.sheets.is_empty() {
+            return invalid("시트가 하나도 없습니다".to_string());
+        }
+
+        let mut sheet_names = HashSet::new();
+        for sheet in &self.sheets {
+            if sheet.name.trim().is_empty() {
+                return invalid("이름 없는 시트가 있습니다".to_string());
+            }
+            if !sheet_names.insert(sheet.name.as_str()) {
+                return invalid(format!("시트 이름이 겹칩니다: {}", sheet.name));
+            }
+            if sheet.columns.is_empty() {
+                return invalid(format!("{}: 열이 하나도 없습니다", sheet.name));
+            }
+
+            let mut column_names = HashSet::new();
+            for column in &sheet.columns {
+                if column.name.trim().is_empty() {
+                    return invalid(format!("{}: 이름 없는 열이 있습니다", sheet.name));
+                }
+                if !column_names.insert(column.name.as_str()) {
+                    return invalid(format!("{}: 열 이름이 겹칩니다: {}", sheet.name, column.name));
+                }
+                if !column.values.is_empty() && column.kind != ColumnType::Text {
+                    return invalid(format!("{}.{}: 드롭다운 목록(values)은 text 열에만 쓸 수 있습니다", sheet.name, column.name));
+                }
+                if column.format.is_some() && matches!(column.kind, ColumnType::Text | ColumnType::Boolean) {
+                    return invalid(format!("{}.{}: 표시 형식(format)은 number, currency, date 열에만 쓸 수 있습니다", sheet.name, column.name));
+                }
+                if column.width == Some(0) {
+                    return invalid(format!("{}.{}: 열 너비(width)는 1 이상이어야 합니다", sheet.name, column.name));
+                }
+            }
+
+            for color in std::iter::once(&sheet.header_color).chain(sheet.highlights.iter().map(|highlight| &highlight.color)) {
+                if parse_color(color).is_none() {
+                    return invalid(format!("{}: 색은 #RRGGBB 로 적습니다: {}", sheet.name, color));
+                }
+            }
+            for highlight in &sheet.highlights {
+                if let Err(message) = sheet.highlight_formula(&highlight.when) {
+                    return invalid(format!("{}: {}", sheet.name, message));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SheetSchema {
+    // {project} 를 채운 시트 이름
+    pub fn title(&self, project_name: &str) -> String {
+        self.name.replace("{project}", project_name)
+    }
+
+    pub fn headers(&self) -> Vec<String> {
+        self.columns.iter().map(|column| column.name.clone()).collect()
+    }
+
+    // 조건부 서식 수식 ({End_Date} → $J2, 첫 데이터 행 기준이라 행마다 따라 내려간다)
+    pub fn highlight_formula(&self, when: &str) -> Result<String, String> {
+        let mut formula = "=".to_string();
+        let mut rest = when.trim().trim_start_matches('=');
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}').ok_or_else(|| format!("{{ 가 닫히지 않았습니다: {}", when))? + start;
+            let name = &rest[start + 1..end];
+            let index = self.columns.iter().position(|column| column.name == name).ok_or_else(|| format!("조건의 {{{}}} 열이 없습니다", name))?;
+            formula.push_str(&rest[..start]);
+            formula.push_str(&format!("${}2", column_letter(index + 1)));
+            rest = &rest[end + 1..];
+        }
+        formula.push_str(rest);
+        Ok(formula)
+    }
+}
+
+// "#RRGGBB" → (빨강, 초록, 파랑) 0.0 ~ 1.0
+pub fn parse_color(hex: &str) -> Option<(f32, f32, f32)> {
+    let hex = hex.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok().map(|value| value as f32 / 255.0);
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
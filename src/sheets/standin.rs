@@ -0,0 +1,183 @@
+use super::{InMemorySheets, SheetsBackend, SheetsError};
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpRequest, HttpResponse};
+use google_sheets4::api::{BatchUpdateSpreadsheetRequest, Spreadsheet, ValueRange};
+use percent_encoding::percent_decode_str;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+// 구글 Sheets v4 REST API 대역 (sheets_standin 바이너리, 종단 간 테스트용)
+// 우리 코드가 쓰는 요청만 InMemorySheets 로 처리한다
+// - POST /token                                         가짜 OAuth 토큰 (서비스 계정 JWT 는 검사하지 않는다)
+// - POST /v4/spreadsheets                               spreadsheets.create
+// - GET  /v4/spreadsheets/{id}                          spreadsheets.get (시트 속성만)
+// - POST /v4/spreadsheets/{id}:batchUpdate              addSheet, setBasicFilter (나머지 요청은 기록만)
+// - GET  /v4/spreadsheets/{id}/values/{range}           values.get
+// - PUT  /v4/spreadsheets/{id}/values/{range}           values.update
+// - POST /v4/spreadsheets/{id}/values/{range}:append    values.append
+// - POST /v4/spreadsheets/{id}/values/{range}:clear     values.clear
+// 토큰 외의 요청은 Authorization: Bearer 헤더가 있어야 한다
+const ACCESS_TOKEN: &str = "standin-access-token";
+
+// 구글 API 형식의 에러 응답
+fn error_response(status: StatusCode, message: &str) -> HttpResponse {
+    let status_name = match status {
+        StatusCode::BAD_REQUEST => "INVALID_ARGUMENT",
+        StatusCode::UNAUTHORIZED => "UNAUTHENTICATED",
+        StatusCode::NOT_FOUND => "NOT_FOUND",
+        _ => "INTERNAL",
+    };
+    HttpResponse::build(status).json(json!({ "error": { "code": status.as_u16(), "message": message, "status": status_name } }))
+}
+
+fn sheets_error_response(e: SheetsError) -> HttpResponse {
+    let status = match e {
+        SheetsError::NotFound(_) => StatusCode::NOT_FOUND,
+        SheetsError::BadRequest(_) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    error_response(status, &e.to_string())
+}
+
+fn authorized(req: &HttpRequest) -> bool {
+    req.headers().get("Authorization").and_then(|v| v.to_str().ok()).is_some_and(|v| v.starts_with("Bearer "))
+}
+
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, SheetsError> {
+    serde_json::from_slice(body).map_err(|e| SheetsError::BadRequest(format!("요청 본문을 읽을 수 없습니다: {}", e)))
+}
+
+// ValueRange 의 셀을 문자열로 (숫자 등은 JSON 표기 그대로)
+fn value_rows(value_range: ValueRange) -> Vec<Vec<String>> {
+    let cell = |value: Value| match value {
+        Value::String(s) => s,
+        other => other.to_string(),
+    };
+    value_range.values.unwrap_or_default().into_iter().map(|row| row.into_iter().map(cell).collect()).collect()
+}
+
+// POST /token
+async fn token() -> HttpResponse {
+    HttpResponse::Ok().json(json!({ "access_token": ACCESS_TOKEN, "token_type": "Bearer", "expires_in": 3600 }))
+}
+
+// POST /v4/spreadsheets
+async fn create_spreadsheet(req: HttpRequest, sheets: web::Data<InMemorySheets>, body: web::Bytes) -> HttpResponse {
+    if !authorized(&req) {
+        return error_response(StatusCode::UNAUTHORIZED, "인증 토큰이 없습니다");
+    }
+    let spreadsheet: Spreadsheet = match parse_body(&body) {
+        Ok(spreadsheet) => spreadsheet,
+        Err(e) => return sheets_error_response(e),
+    };
+    let title = spreadsheet.properties.and_then(|properties| properties.title).unwrap_or_else(|| "제목 없는 스프레드시트".to_string());
+
+    match sheets.create_spreadsheet(&title).await {
+        Ok(spreadsheet_id) => HttpResponse::Ok().json(json!({
+            "spreadsheetId": spreadsheet_id,
+            "properties": { "title": title },
+            "sheets": [{ "properties": { "sheetId": 0, "title": "Sheet1", "index": 0 } }],
+        })),
+        Err(e) => sheets_error_response(e),
+    }
+}
+
+// /v4/spreadsheets/{id}, /v4/spreadsheets/{id}:batchUpdate, /v4/spreadsheets/{id}/values/{range}[:append|:clear]
+async fn spreadsheet_request(req: HttpRequest, sheets: web::Data<InMemorySheets>, body: web::Bytes) -> HttpResponse {
+    if !authorized(&req) {
+        return error_response(StatusCode::UNAUTHORIZED, "인증 토큰이 없습니다");
+    }
+
+    // 범위는 퍼센트 인코딩되어 온다 (test_sheet%21A1%3AB2)
+    // match_info 는 actix 가 이미 (%, /, + 만 빼고) 디코딩한 값이므로, 원래 경로를 구글처럼 한 번만 디코딩한다
+    let raw_tail = req.uri().path().split_once("/v4/spreadsheets/").map_or("", |(_, tail)| tail);
+    let tail = percent_decode_str(raw_tail).decode_utf8_lossy().into_owned();
+    let method = req.method().as_str();
+
+    let result = if let Some(spreadsheet_id) = tail.strip_suffix(":batchUpdate").filter(|_| method == "POST") {
+        batch_update(&sheets, spreadsheet_id, &body).await
+    } else if method == "GET" && !tail.contains(['/', ':']) {
+        get_spreadsheet(&sheets, &tail).await
+    } else if let Some((spreadsheet_id, range)) = tail.split_once("/values/") {
+        match (method, range.strip_suffix(":append"), range.strip_suffix(":clear")) {
+            ("POST", Some(range), _) => append_values(&sheets, spreadsheet_id, range, &body).await,
+            ("POST", _, Some(range)) => sheets.clear_values(spreadsheet_id, range).await.map(|()| json!({ "spreadsheetId": spreadsheet_id, "clearedRange": range })),
+            ("PUT", _, _) => update_values(&sheets, spreadsheet_id, range, &body).await,
+            ("GET", _, _) => get_values(&sheets, spreadsheet_id, range).await,
+            _ => return error_response(StatusCode::NOT_FOUND, "지원하지 않는 요청입니다"),
+        }
+    } else {
+        return error_response(StatusCode::NOT_FOUND, "지원하지 않는 요청입니다")
+    };
+
+    match result {
+        Ok(value) => HttpResponse::Ok().json(value),
+        Err(e) => sheets_error_response(e),
+    }
+}
+
+async fn get_spreadsheet(sheets: &InMemorySheets, spreadsheet_id: &str) -> Result<Value, SheetsError> {
+    let properties = sheets.get_sheets(spreadsheet_id).await?;
+    let sheets: Vec<Value> = properties.into_iter().map(|properties| json!({ "properties": { "sheetId": properties.sheet_id, "title": properties.title, "index": properties.index } })).collect();
+    Ok(json!({ "spreadsheetId": spreadsheet_id, "sheets": sheets }))
+}
+
+async fn batch_update(sheets: &InMemorySheets, spreadsheet_id: &str, body: &[u8]) -> Result<Value, SheetsError> {
+    let request: BatchUpdateSpreadsheetRequest = parse_body(body)?;
+    let replies = sheets.batch_update(spreadsheet_id, request.requests.unwrap_or_default()).await?;
+
+    // addSheet 외의 요청은 빈 응답
+    let replies: Vec<Value> = replies
+        .into_iter()
+        .map(|reply| match reply.add_sheet.and_then(|add_sheet| add_sheet.properties) {
+            Some(properties) => json!({ "addSheet": { "properties": { "sheetId": properties.sheet_id, "title": properties.title, "index": properties.index } } }),
+            None => json!({}),
+        })
+        .collect();
+    Ok(json!({ "spreadsheetId": spreadsheet_id, "replies": replies }))
+}
+
+async fn append_values(sheets: &InMemorySheets, spreadsheet_id: &str, range: &str, body: &[u8]) -> Result<Value, SheetsError> {
+    let values = value_rows(parse_body(body)?);
+    let result = sheets.append_values(spreadsheet_id, range, values).await?;
+
+    let updates = result.updates.unwrap_or_default();
+    Ok(json!({
+        "spreadsheetId": spreadsheet_id,
+        "tableRange": result.table_range,
+        "updates": {
+            "spreadsheetId": spreadsheet_id,
+            "updatedRange": updates.updated_range,
+            "updatedRows": updates.updated_rows,
+            "updatedColumns": updates.updated_columns,
+            "updatedCells": updates.updated_cells,
+        },
+    }))
+}
+
+async fn update_values(sheets: &InMemorySheets, spreadsheet_id: &str, range: &str, body: &[u8]) -> Result<Value, SheetsError> {
+    let values = value_rows(parse_body(body)?);
+    let rows = values.len();
+    let cells: usize = values.iter().map(Vec::len).sum();
+    sheets.update_values(spreadsheet_id, range, values).await?;
+
+    Ok(json!({ "spreadsheetId": spreadsheet_id, "updatedRange": range, "updatedRows": rows, "updatedCells": cells }))
+}
+
+async fn get_values(sheets: &InMemorySheets, spreadsheet_id: &str, range: &str) -> Result<Value, SheetsError> {
+    let values = sheets.get_values(spreadsheet_id, range).await?;
+
+    // 빈 범위면 API 처럼 values 를 빼고 보낸다
+    let mut value_range = json!({ "range": range, "majorDimension": "ROWS" });
+    if !values.is_empty() {
+        value_range["values"] = json!(values);
+    }
+    Ok(value_range)
+}
+
+// 대역 서버 라우트 등록 (App::new().app_data(web::Data::new(InMemorySheets::new())).configure(configure_standin_routes))
+pub fn configure_standin_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/token", web::post().to(token))
+        .route("/v4/spreadsheets", web::post().to(create_spreadsheet))
+        .route("/v4/spreadsheets/{tail:.*}", web::route().to(spreadsheet_request));
+}
@@ -0,0 +1,13 @@
+use hello::helpers;
+
+// 라이브러리 밖(src/bin, tests/)에서 helpers 모듈을 쓸 수 있는지 확인
+//   cargo test --test helpers -- --nocapture
+//   출력:
+//   mod_string::function()
+//   mod_number::add_number() : 5
+
+#[test]
+fn helpers_are_usable_outside_the_crate() {
+    helpers::function_string();
+    helpers::function_number();
+}
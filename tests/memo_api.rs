@@ -0,0 +1,619 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use chrono::Utc;
+use futures_util::{FutureExt, StreamExt};
+use hello::memo::{self, AppState, AuditEntry, AuditLog, ContentCipher, Memo};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+// 메모 API 통합 테스트 (서버를 띄우지 않고 actix 테스트 서비스로 호출)
+//   cargo test --test memo_api
+
+// 빈 상태의 메모 API 서비스
+macro_rules! memo_app {
+    () => {
+        test::init_service(App::new().app_data(web::Data::new(AppState::default())).configure(memo::configure_routes)).await
+    };
+}
+
+// JSON 을 보내고 (상태 코드, 응답 본문) 을 돌려준다
+macro_rules! send {
+    ($app:expr, $req:expr) => {{
+        let res = test::call_service(&$app, $req.to_request()).await;
+        let status = res.status();
+        (status, test::read_body(res).await)
+    }};
+}
+
+// 메모를 만들고 응답 JSON 을 돌려준다
+macro_rules! create_memo {
+    ($app:expr, $body:expr) => {{
+        let (status, body) = send!($app, test::TestRequest::post().uri("/memos").set_json($body));
+        assert_eq!(status, StatusCode::OK, "{}", String::from_utf8_lossy(&body));
+        serde_json::from_slice::<Value>(&body).unwrap()
+    }};
+}
+
+fn get(uri: &str) -> test::TestRequest {
+    test::TestRequest::get().uri(uri)
+}
+
+fn json_of(body: &[u8]) -> Value {
+    serde_json::from_slice(body).unwrap()
+}
+
+// 본문 암호화 키 "<키 ID>:<base64 32바이트>" (fill 로 키마다 다른 바이트를 채운다)
+fn key(id: &str, fill: char) -> String {
+    format!("{}:{}A=", id, fill.to_string().repeat(42))
+}
+
+#[actix_web::test]
+async fn create_get_update_delete_memo() {
+    let app = memo_app!();
+
+    let memo = create_memo!(app, json!({ "title": "장보기", "content": "우유" }));
+    assert_eq!(memo["title"], "장보기");
+    let id = memo["id"].as_u64().unwrap();
+
+    let (status, body) = send!(app, get(&format!("/memos/{}", id)));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body)["content"], "우유");
+
+    let (status, body) = send!(app, test::TestRequest::put().uri(&format!("/memos/{}", id)).set_json(json!({ "title": "장보기", "content": "우유, 계란" })));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body)["content"], "우유, 계란");
+
+    let (_, body) = send!(app, get("/memos"));
+    let memos = json_of(&body);
+    assert_eq!(memos.as_array().unwrap().len(), 1);
+    assert_eq!(memos[0]["content"], "우유, 계란");
+
+    let (status, _) = send!(app, test::TestRequest::delete().uri(&format!("/memos/{}", id)));
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send!(app, get(&format!("/memos/{}", id)));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn missing_resources_return_404() {
+    let app = memo_app!();
+
+    for uri in ["/memos/42", "/memos/42/html", "/memos/42/links", "/memos/42/backlinks", "/notebooks/42"] {
+        let (status, _) = send!(app, get(uri));
+        assert_eq!(status, StatusCode::NOT_FOUND, "GET {}", uri);
+    }
+
+    let memo = json!({ "title": "없음", "content": "" });
+    let (status, _) = send!(app, test::TestRequest::put().uri("/memos/42").set_json(&memo));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send!(app, test::TestRequest::delete().uri("/memos/42"));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send!(app, test::TestRequest::put().uri("/memos/42/notebook").set_json(json!({ "notebook_id": null })));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let notebook = json!({ "name": "없음" });
+    let (status, _) = send!(app, test::TestRequest::put().uri("/notebooks/42").set_json(&notebook));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send!(app, test::TestRequest::delete().uri("/notebooks/42"));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send!(app, test::TestRequest::post().uri("/memos/from-template/missing").set_json(json!({})));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // 숫자가 아닌 ID 는 라우트에 맞지 않는다
+    let (status, _) = send!(app, get("/memos/abc"));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn malformed_json_returns_400() {
+    let app = memo_app!();
+    let raw = |method: test::TestRequest, uri: &str, body: &'static str| method.uri(uri).insert_header(("Content-Type", "application/json")).set_payload(body);
+
+    let (status, _) = send!(app, raw(test::TestRequest::post(), "/memos", "{\"title\": "));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 필수 필드(content) 누락
+    let (status, _) = send!(app, raw(test::TestRequest::post(), "/memos", "{\"title\": \"제목\"}"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 반복 규칙은 daily/weekly 만
+    let (status, _) = send!(app, raw(test::TestRequest::post(), "/memos", "{\"title\": \"제목\", \"content\": \"\", \"recurrence\": \"hourly\"}"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let id = create_memo!(app, json!({ "title": "제목", "content": "" }))["id"].as_u64().unwrap();
+    let (status, _) = send!(app, raw(test::TestRequest::put(), &format!("/memos/{}", id), "not json"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send!(app, raw(test::TestRequest::post(), "/notebooks", "[]"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = send!(app, raw(test::TestRequest::post(), "/templates", "{"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 잘못된 쿼리 값
+    let (status, _) = send!(app, get("/memos/due?before=yesterday"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 실패한 요청은 아무것도 만들지 않는다
+    let (_, body) = send!(app, get("/memos"));
+    assert_eq!(json_of(&body).as_array().unwrap().len(), 1);
+}
+
+#[actix_web::test]
+async fn concurrent_creates_get_unique_ids() {
+    let app = memo_app!();
+
+    let requests = (0..50).map(|i| test::call_service(&app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": format!("메모 {}", i), "content": "" })).to_request()));
+    let responses = futures_util::future::join_all(requests).await;
+
+    let mut ids = Vec::new();
+    for res in responses {
+        assert_eq!(res.status(), StatusCode::OK);
+        let memo: Value = test::read_body_json(res).await;
+        ids.push(memo["id"].as_u64().unwrap());
+    }
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 50);
+
+    let (_, body) = send!(app, get("/memos"));
+    assert_eq!(json_of(&body).as_array().unwrap().len(), 50);
+}
+
+#[actix_web::test]
+async fn search_and_html_rendering() {
+    let app = memo_app!();
+    create_memo!(app, json!({ "title": "Rust 공부", "content": "**소유권**" }));
+    let id = create_memo!(app, json!({ "title": "장보기", "content": "<script>alert(1)</script>우유" }))["id"].as_u64().unwrap();
+
+    let (_, body) = send!(app, get("/memos?q=rust"));
+    let found = json_of(&body);
+    assert_eq!(found.as_array().unwrap().len(), 1);
+    assert_eq!(found[0]["title"], "Rust 공부");
+
+    let (_, body) = send!(app, get("/memos?render=html&q=%EC%86%8C%EC%9C%A0%EA%B6%8C"));
+    assert_eq!(json_of(&body)[0]["content_html"], "<p><strong>소유권</strong></p>\n");
+
+    let (status, body) = send!(app, get(&format!("/memos/{}/html", id)));
+    assert_eq!(status, StatusCode::OK);
+    let html = String::from_utf8_lossy(&body);
+    assert!(!html.contains("<script>"), "{}", html);
+    assert!(html.contains("우유"));
+}
+
+#[actix_web::test]
+async fn due_memos_and_tasks() {
+    let app = memo_app!();
+    create_memo!(app, json!({ "title": "늦은 일", "content": "- [ ] 보고서\n- [x] 메일", "due_at": "2020-01-01T00:00:00Z" }));
+    create_memo!(app, json!({ "title": "나중 일", "content": "- [ ] 여행 준비", "due_at": "2999-01-01T00:00:00Z" }));
+    create_memo!(app, json!({ "title": "마감 없음", "content": "그냥 메모" }));
+
+    let (_, body) = send!(app, get("/memos/due"));
+    let due = json_of(&body);
+    assert_eq!(due.as_array().unwrap().len(), 1);
+    assert_eq!(due[0]["title"], "늦은 일");
+
+    let (_, body) = send!(app, get("/memos/due?before=3000-01-01T00:00:00Z"));
+    assert_eq!(json_of(&body).as_array().unwrap().len(), 2);
+
+    let (_, body) = send!(app, get("/memos/tasks"));
+    assert_eq!(json_of(&body).as_array().unwrap().len(), 3);
+
+    let (_, body) = send!(app, get("/memos/tasks?checked=false"));
+    let open: Vec<String> = json_of(&body).as_array().unwrap().iter().map(|task| task["text"].as_str().unwrap().to_string()).collect();
+    assert_eq!(open, ["보고서", "여행 준비"]);
+}
+
+#[actix_web::test]
+async fn wiki_links_and_backlinks() {
+    let app = memo_app!();
+    let target = create_memo!(app, json!({ "title": "Rust", "content": "" }))["id"].as_u64().unwrap();
+    let source = create_memo!(app, json!({ "title": "공부 계획", "content": "[[rust]] 와 [[없는 메모]]" }))["id"].as_u64().unwrap();
+
+    let (_, body) = send!(app, get(&format!("/memos/{}/links", source)));
+    let links = json_of(&body);
+    assert_eq!(links["links"][0]["memo_id"], target);
+    assert_eq!(links["dangling"], json!(["없는 메모"]));
+
+    let (_, body) = send!(app, get(&format!("/memos/{}/backlinks", target)));
+    assert_eq!(json_of(&body)[0]["memo_id"], source);
+
+    // 대상이 지워지면 끊어진 링크가 된다
+    send!(app, test::TestRequest::delete().uri(&format!("/memos/{}", target)));
+    let (_, body) = send!(app, get("/memos/links/dangling"));
+    let dangling = json_of(&body);
+    let targets: Vec<&str> = dangling.as_array().unwrap().iter().map(|link| link["target"].as_str().unwrap()).collect();
+    assert_eq!(targets, ["rust", "없는 메모"]);
+}
+
+#[actix_web::test]
+async fn removed_link_sources_drop_out_of_backlinks() {
+    let app = memo_app!();
+    let target = create_memo!(app, json!({ "title": "Rust", "content": "" }))["id"].as_u64().unwrap();
+    let source = create_memo!(app, json!({ "title": "공부 계획", "content": "[[Rust]] 와 [[없는 메모]]" }))["id"].as_u64().unwrap();
+    let (_, body) = send!(app, test::TestRequest::post().uri("/notebooks").set_json(json!({ "name": "메모장" })));
+    let notebook = json_of(&body)["id"].as_u64().unwrap();
+    create_memo!(app, json!({ "title": "노트북 메모", "content": "[[Rust]] [[또 없는 메모]]", "notebook_id": notebook }));
+
+    // 링크를 가진 메모를 지우고, 노트북째로도 지운다
+    send!(app, test::TestRequest::delete().uri(&format!("/memos/{}", source)));
+    let (status, _) = send!(app, test::TestRequest::delete().uri(&format!("/notebooks/{}?mode=cascade", notebook)));
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send!(app, get(&format!("/memos/{}/backlinks", target)));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body), json!([]));
+    let (status, body) = send!(app, get("/memos/links/dangling"));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body), json!([]));
+}
+
+#[actix_web::test]
+async fn notebooks_move_and_delete() {
+    let app = memo_app!();
+    let create_notebook = |name: &str, parent_id: Option<u64>| test::TestRequest::post().uri("/notebooks").set_json(json!({ "name": name, "parent_id": parent_id }));
+
+    let (_, body) = send!(app, create_notebook("업무", None));
+    let work = json_of(&body)["id"].as_u64().unwrap();
+    let (_, body) = send!(app, create_notebook("회의", Some(work)));
+    let meetings = json_of(&body)["id"].as_u64().unwrap();
+
+    let (status, _) = send!(app, create_notebook("고아", Some(999)));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send!(app, get("/notebooks"));
+    assert_eq!(json_of(&body).as_array().unwrap().len(), 2);
+
+    let memo = create_memo!(app, json!({ "title": "주간 회의", "content": "", "notebook_id": meetings }))["id"].as_u64().unwrap();
+    let (status, _) = send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "x", "content": "", "notebook_id": 999 })));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send!(app, get(&format!("/notebooks/{}", work)));
+    assert_eq!(json_of(&body)["memos"].as_array().unwrap().len(), 0);
+    let (_, body) = send!(app, get(&format!("/notebooks/{}?recursive=true", work)));
+    assert_eq!(json_of(&body)["memos"][0]["id"], memo);
+
+    // 자기 하위로는 옮길 수 없다
+    let (status, _) = send!(app, test::TestRequest::put().uri(&format!("/notebooks/{}", work)).set_json(json!({ "name": "업무", "parent_id": meetings })));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = send!(app, test::TestRequest::put().uri(&format!("/memos/{}/notebook", memo)).set_json(json!({ "notebook_id": work })));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body)["notebook_id"], work);
+
+    // reparent(기본): 하위 노트북과 메모가 최상위로 올라온다
+    let (status, _) = send!(app, test::TestRequest::delete().uri(&format!("/notebooks/{}", work)));
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = send!(app, get(&format!("/memos/{}", memo)));
+    assert_eq!(json_of(&body)["notebook_id"], Value::Null);
+    let (_, body) = send!(app, get("/notebooks"));
+    assert_eq!(json_of(&body)[0]["parent_id"], Value::Null);
+
+    // cascade: 메모까지 삭제
+    send!(app, test::TestRequest::put().uri(&format!("/memos/{}/notebook", memo)).set_json(json!({ "notebook_id": meetings })));
+    let (status, _) = send!(app, test::TestRequest::delete().uri(&format!("/notebooks/{}?mode=cascade", meetings)));
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send!(app, get(&format!("/memos/{}", memo)));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn templates_fill_variables() {
+    let app = memo_app!();
+
+    let (status, body) = send!(app, test::TestRequest::post().uri("/templates").set_json(json!({ "name": "meeting", "title": "{{topic}} 회의", "content": "작성자: {{user}}\n{{agenda}}" })));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body)["variables"], json!(["topic", "agenda"]));
+
+    let (_, body) = send!(app, get("/templates"));
+    assert_eq!(json_of(&body).as_array().unwrap().len(), 1);
+
+    let (status, body) = send!(app, test::TestRequest::post().uri("/memos/from-template/meeting").set_json(json!({ "variables": { "topic": "주간" } })));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json_of(&body)["missing"], json!(["agenda"]));
+
+    let (status, body) = send!(
+        app,
+        test::TestRequest::post().uri("/memos/from-template/meeting").insert_header(("X-User", "kim")).set_json(json!({ "variables": { "topic": "주간", "agenda": "일정 공유" } }))
+    );
+    assert_eq!(status, StatusCode::OK);
+    let memo = json_of(&body);
+    assert_eq!(memo["title"], "주간 회의");
+    assert_eq!(memo["content"], "작성자: kim\n일정 공유");
+}
+
+#[actix_web::test]
+async fn audit_log_records_changes() {
+    let app = memo_app!();
+    let id = create_memo!(app, json!({ "title": "감사", "content": "처음" }))["id"].as_u64().unwrap();
+    send!(app, test::TestRequest::put().uri(&format!("/memos/{}", id)).insert_header(("X-User", "lee")).set_json(json!({ "title": "감사", "content": "수정" })));
+    send!(app, test::TestRequest::delete().uri(&format!("/memos/{}", id)));
+    create_memo!(app, json!({ "title": "다른 메모", "content": "" }));
+
+    let (status, body) = send!(app, get(&format!("/audit?memo_id={}", id)));
+    assert_eq!(status, StatusCode::OK);
+    let entries: Vec<AuditEntry> = serde_json::from_slice(&body).unwrap();
+    let actors: Vec<&str> = entries.iter().map(|entry| entry.actor.as_str()).collect();
+    assert_eq!(actors, ["anonymous", "lee", "anonymous"]);
+
+    let (_, body) = send!(app, get("/audit"));
+    let entries: Vec<AuditEntry> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(entries.len(), 4);
+    assert!(memo::verify_audit_chain(&entries).is_ok());
+}
+
+#[actix_web::test]
+async fn audit_log_file_survives_restart_and_detects_tampering() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.log");
+    let path = path.to_str().unwrap();
+
+    let app = test::init_service(App::new().app_data(web::Data::new(AppState::new(AuditLog::open(path).unwrap(), None))).configure(memo::configure_routes)).await;
+    let id = create_memo!(app, json!({ "title": "감사", "content": "처음" }))["id"].as_u64().unwrap();
+    send!(app, test::TestRequest::put().uri(&format!("/memos/{}", id)).insert_header(("X-User", "lee")).set_json(json!({ "title": "감사", "content": "수정" })));
+    drop(app);
+
+    // 다시 열면 (메모 저장소는 비어도) 파일의 마지막 항목 뒤로 체인을 이어간다
+    let app = test::init_service(App::new().app_data(web::Data::new(AppState::new(AuditLog::open(path).unwrap(), None))).configure(memo::configure_routes)).await;
+    create_memo!(app, json!({ "title": "재시작 후", "content": "" }));
+    let (status, body) = send!(app, get("/audit"));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(serde_json::from_slice::<Vec<AuditEntry>>(&body).unwrap().len(), 3);
+
+    let entries = memo::read_audit_file(path).unwrap();
+    assert_eq!(entries.len(), 3);
+    assert!(memo::verify_audit_chain(&entries).is_ok());
+
+    // 한 항목만 고치면 그 항목에서 체인이 끊어진다
+    let lines: Vec<String> = std::fs::read_to_string(path).unwrap().lines().map(|line| line.replace("\"lee\"", "\"kim\"")).collect();
+    std::fs::write(path, lines.join("\n") + "\n").unwrap();
+    let err = memo::verify_audit_chain(&memo::read_audit_file(path).unwrap()).unwrap_err();
+    assert_eq!(err, "항목 1 의 내용이 해시와 일치하지 않습니다 (변조)");
+}
+
+#[actix_web::test]
+async fn cipher_round_trips_and_reads_old_keys() {
+    let old = ContentCipher::new(&key("k1", 'A'), &[]).unwrap();
+    let sealed = old.seal("비밀 본문");
+    assert!(sealed.starts_with("enc:v1:k1:"));
+    assert_eq!(old.open(&sealed).unwrap(), "비밀 본문");
+    // 암호화를 켜기 전에 저장된 평문은 그대로
+    assert_eq!(old.open("평문").unwrap(), "평문");
+
+    // MEMO_ENCRYPTION_OLD_KEYS 에 있는 키로 저장된 본문도 읽지만 다시 암호화해야 한다
+    let new = ContentCipher::new(&key("k2", 'B'), &[&key("k1", 'A')]).unwrap();
+    assert_eq!(new.open(&sealed).unwrap(), "비밀 본문");
+    assert!(new.needs_rotation(&sealed));
+    assert!(!new.needs_rotation(&new.seal("비밀 본문")));
+}
+
+#[actix_web::test]
+async fn cipher_rejects_tampered_or_unknown_key() {
+    let cipher = ContentCipher::new(&key("k1", 'A'), &[]).unwrap();
+    let sealed = cipher.seal("비밀 본문");
+
+    // 암호문 가운데 한 글자만 바꿔도 인증 태그가 맞지 않는다
+    let mut tampered = sealed.clone().into_bytes();
+    let i = "enc:v1:k1:".len() + 20;
+    tampered[i] = if tampered[i] == b'A' { b'B' } else { b'A' };
+    assert_eq!(cipher.open(&String::from_utf8(tampered).unwrap()).unwrap_err(), "키 k1 로 복호화할 수 없습니다");
+
+    let other = ContentCipher::new(&key("k2", 'B'), &[]).unwrap();
+    assert_eq!(other.open(&sealed).unwrap_err(), "키 k1 가 없습니다");
+}
+
+#[actix_web::test]
+async fn encrypted_memos_rotate_to_new_key_at_runtime() {
+    let cipher = ContentCipher::new(&key("k1", 'A'), &[]).unwrap();
+    let data = web::Data::new(AppState::new(AuditLog::in_memory(), Some(cipher)));
+    let app = test::init_service(App::new().app_data(data.clone()).configure(memo::configure_routes)).await;
+    let id = create_memo!(app, json!({ "title": "비밀", "content": "본문" }))["id"].as_u64().unwrap();
+    let read_content = || async { json_of(&send!(app, get(&format!("/memos/{}", id))).1)["content"].clone() };
+    assert_eq!(read_content().await, "본문");
+
+    // 이전 키를 빠뜨리면 저장된 본문을 잃으므로 바꾸지 않는다
+    let err = data.replace_cipher(ContentCipher::new(&key("k2", 'B'), &[]).unwrap()).unwrap_err();
+    assert!(err.contains(&format!("메모 {}", id)), "{}", err);
+
+    // 이전 키로 저장된 본문은 다시 암호화하기 전에도 읽힌다
+    data.replace_cipher(ContentCipher::new(&key("k2", 'B'), &[&key("k1", 'A')]).unwrap()).unwrap();
+    assert_eq!(read_content().await, "본문");
+    assert_eq!(memo::run_key_rotation(data.clone()).await, 1);
+    assert_eq!(memo::run_key_rotation(data.clone()).await, 0);
+
+    // 모두 새 키로 옮겼으므로 이전 키 없이도 읽힌다
+    data.replace_cipher(ContentCipher::new(&key("k2", 'B'), &[]).unwrap()).unwrap();
+    assert_eq!(read_content().await, "본문");
+}
+
+#[actix_web::test]
+async fn admin_endpoint_rotates_keys() {
+    let rotate = |token: &str, body: Value| test::TestRequest::post().uri("/admin/rotate-keys").insert_header(("X-Admin-Token", token)).set_json(body);
+    let new_keys = json!({ "active_key": key("k2", 'B'), "old_keys": [key("k1", 'A')] });
+
+    // 관리자 토큰을 설정하지 않으면 열리지 않는다
+    let app = memo_app!();
+    assert_eq!(send!(app, rotate("admin", new_keys.clone())).0, StatusCode::NOT_FOUND);
+
+    let cipher = ContentCipher::new(&key("k1", 'A'), &[]).unwrap();
+    let data = web::Data::new(AppState::new(AuditLog::in_memory(), Some(cipher)).with_admin_token(Some("admin".to_string())));
+    let app = test::init_service(App::new().app_data(data).configure(memo::configure_routes)).await;
+    let id = create_memo!(app, json!({ "title": "비밀", "content": "본문" }))["id"].as_u64().unwrap();
+
+    assert_eq!(send!(app, rotate("guess", new_keys.clone())).0, StatusCode::FORBIDDEN);
+    assert_eq!(send!(app, rotate("admin", json!({ "active_key": key("k2", 'B') }))).0, StatusCode::BAD_REQUEST);
+    assert_eq!(send!(app, rotate("admin", json!({ "active_key": "k2:짧음" }))).0, StatusCode::BAD_REQUEST);
+
+    let (status, body) = send!(app, rotate("admin", new_keys));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body), json!({ "key_id": "k2", "rotated": 1 }));
+    assert_eq!(json_of(&send!(app, get(&format!("/memos/{}", id))).1)["content"], "본문");
+}
+
+#[actix_web::test]
+async fn reminders_stream_is_event_stream() {
+    let app = memo_app!();
+
+    // 본문은 알림이 올 때까지 끝나지 않으므로 헤더만 확인한다
+    let res = test::call_service(&app, get("/memos/reminders").to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("Content-Type").unwrap(), "text/event-stream");
+}
+
+#[actix_web::test]
+async fn expired_memos_disappear_and_are_swept() {
+    let data = web::Data::new(AppState::default());
+    let app = test::init_service(App::new().app_data(data.clone()).configure(memo::configure_routes)).await;
+
+    // 조회는 현재 시각으로 만료를 판단하므로 곧 만료되는 메모를 만든다 (스위퍼는 시각을 정해서 부른다)
+    let expires_at = Utc::now() + chrono::Duration::milliseconds(100);
+    let scratch = create_memo!(app, json!({ "title": "임시", "content": "[[남는 메모]]", "expires_at": expires_at }));
+    let kept = create_memo!(app, json!({ "title": "남는 메모", "content": "", "ttl_seconds": 3600 }));
+    assert!(kept["expires_at"].is_string());
+
+    // 둘 다 주거나 이미 지난 시각이면 400
+    let (status, _) = send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "x", "content": "", "ttl_seconds": 60, "expires_at": "2999-01-01T00:00:00Z" })));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "x", "content": "", "expires_at": "2000-01-01T00:00:00Z" })));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 아직 만료 전이면 스위퍼가 지우지 않는다
+    assert_eq!(memo::sweep_expired(&data, expires_at - chrono::Duration::milliseconds(1)), 0);
+
+    let wait = (expires_at - Utc::now()).to_std().unwrap_or_default();
+    tokio::time::sleep(wait + Duration::from_millis(10)).await;
+
+    // 스위퍼가 돌기 전에도 모든 조회에서 빠진다
+    let scratch_uri = format!("/memos/{}", scratch["id"]);
+    let (status, _) = send!(app, get(&scratch_uri));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send!(app, test::TestRequest::put().uri(&scratch_uri).set_json(json!({ "title": "임시", "content": "" })));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, body) = send!(app, get("/memos"));
+    let ids: Vec<u64> = json_of(&body).as_array().unwrap().iter().map(|memo| memo["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, [kept["id"].as_u64().unwrap()]);
+    let (_, body) = send!(app, get(&format!("/memos/{}/backlinks", kept["id"])));
+    assert_eq!(json_of(&body), json!([]));
+
+    // 스위퍼가 지우면 감사 로그에 삭제로 남는다
+    assert_eq!(memo::sweep_expired(&data, expires_at), 1);
+    assert_eq!(memo::sweep_expired(&data, expires_at), 0);
+    let (_, body) = send!(app, get(&format!("/audit?memo_id={}", scratch["id"])));
+    let entries: Vec<AuditEntry> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].actor, "expiry");
+}
+
+#[actix_web::test]
+async fn update_keeps_expiry_and_notebook() {
+    let app = memo_app!();
+    let (_, body) = send!(app, test::TestRequest::post().uri("/notebooks").set_json(json!({ "name": "업무" })));
+    let notebook = json_of(&body)["id"].as_u64().unwrap();
+
+    let memo = create_memo!(app, json!({ "title": "임시", "content": "", "ttl_seconds": 3600, "notebook_id": notebook }));
+    let uri = format!("/memos/{}", memo["id"]);
+
+    // 만료 시각과 노트북을 빼고 보내면 그대로 남는다
+    let (status, body) = send!(app, test::TestRequest::put().uri(&uri).set_json(json!({ "title": "임시", "content": "수정" })));
+    assert_eq!(status, StatusCode::OK);
+    let updated = json_of(&body);
+    assert_eq!(updated["expires_at"], memo["expires_at"]);
+    assert_eq!(updated["notebook_id"], notebook);
+    let (_, body) = send!(app, get(&uri));
+    assert_eq!(json_of(&body)["expires_at"], memo["expires_at"]);
+
+    // 주면 바뀐다 (없는 노트북이면 400)
+    let (status, body) = send!(app, test::TestRequest::put().uri(&uri).set_json(json!({ "title": "임시", "content": "", "expires_at": "2999-01-01T00:00:00Z" })));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body)["expires_at"], "2999-01-01T00:00:00Z");
+    let (status, _) = send!(app, test::TestRequest::put().uri(&uri).set_json(json!({ "title": "임시", "content": "", "notebook_id": 999 })));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn mounted_under_path_prefix() {
+    // 다른 앱의 라우트와 함께 접두사 아래에 마운트
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(AppState::default()))
+            .route("/health", web::get().to(|| async { "ok" }))
+            .service(web::scope("/memo-api").configure(memo::configure_routes)),
+    )
+    .await;
+
+    let (status, _) = send!(app, get("/health"));
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send!(app, test::TestRequest::post().uri("/memo-api/memos").set_json(json!({ "title": "접두사", "content": "" })));
+    assert_eq!(status, StatusCode::OK);
+    let memo: Memo = serde_json::from_slice(&body).unwrap();
+
+    let (status, body) = send!(app, get(&format!("/memo-api/memos/{}", memo.id)));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(serde_json::from_slice::<Memo>(&body).unwrap().title, "접두사");
+
+    // 접두사 없는 경로는 없다
+    let (status, _) = send!(app, get("/memos"));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn graphql_shares_storage_with_rest() {
+    let app = memo_app!();
+    create_memo!(app, json!({ "title": "REST 메모", "content": "" }));
+    let query = |query: &str| test::TestRequest::post().uri("/graphql").set_json(json!({ "query": query }));
+
+    let (status, body) = send!(app, query(r#"mutation { createMemo(input: { title: "GraphQL 메모", content: "", recurrence: WEEKLY }) { id recurrence } }"#));
+    assert_eq!(status, StatusCode::OK);
+    let created = json_of(&body);
+    assert_eq!(created["data"]["createMemo"]["recurrence"], "WEEKLY");
+    let id = created["data"]["createMemo"]["id"].as_u64().unwrap();
+
+    // REST 에서도 같은 메모가 보인다
+    let (status, _) = send!(app, get(&format!("/memos/{}", id)));
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send!(app, query(r#"{ memos(filter: { q: "메모" }, offset: 1, limit: 1) { totalCount hasNextPage items { title } } }"#));
+    let page = &json_of(&body)["data"]["memos"];
+    assert_eq!(page["totalCount"], 2);
+    assert_eq!(page["hasNextPage"], false);
+    assert_eq!(page["items"][0]["title"], "GraphQL 메모");
+
+    let (_, body) = send!(app, query(r#"mutation { updateMemo(id: 999, input: { title: "없음", content: "" }) { id } }"#));
+    assert_eq!(json_of(&body)["errors"][0]["message"], "메모를 찾을 수 없습니다");
+
+    send!(app, query(&format!("mutation {{ deleteMemo(id: {}) {{ id }} }}", id)));
+    let (_, body) = send!(app, query(&format!("{{ memo(id: {}) {{ id }} }}", id)));
+    assert_eq!(json_of(&body)["data"]["memo"], Value::Null);
+}
+
+#[actix_web::test]
+async fn graphql_subscription_streams_changes() {
+    let data = web::Data::new(AppState::default());
+    let app = test::init_service(App::new().app_data(data.clone()).configure(memo::configure_routes)).await;
+    let watched = create_memo!(app, json!({ "title": "구독할 메모", "content": "" }))["id"].as_u64().unwrap();
+
+    // 첫 poll 에서 리졸버가 실행되어 변경 채널을 구독한다 (아직 이벤트는 없다)
+    let query = format!("subscription {{ memoChanges(memoId: {}) {{ kind memoId memo {{ title }} }} }}", watched);
+    let mut stream = memo::graphql_schema().execute_stream(async_graphql::Request::new(query).data(data.clone()));
+    assert!(stream.next().now_or_never().is_none());
+
+    // 다른 메모의 변경은 걸러진다
+    create_memo!(app, json!({ "title": "다른 메모", "content": "" }));
+    let (status, _) = send!(app, test::TestRequest::put().uri(&format!("/memos/{}", watched)).set_json(json!({ "title": "제목 변경", "content": "" })));
+    assert_eq!(status, StatusCode::OK);
+
+    let response = tokio::time::timeout(Duration::from_secs(5), stream.next()).await.expect("구독 이벤트가 오지 않았습니다").unwrap();
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let change = &response.data.into_json().unwrap()["memoChanges"];
+    assert_eq!(change["kind"], "UPDATE");
+    assert_eq!(change["memoId"], watched);
+    assert_eq!(change["memo"]["title"], "제목 변경");
+
+    send!(app, test::TestRequest::delete().uri(&format!("/memos/{}", watched)));
+    let response = tokio::time::timeout(Duration::from_secs(5), stream.next()).await.expect("구독 이벤트가 오지 않았습니다").unwrap();
+    let change = &response.data.into_json().unwrap()["memoChanges"];
+    assert_eq!(change["kind"], "DELETE");
+    assert_eq!(change["memo"], Value::Null);
+}
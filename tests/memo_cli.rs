@@ -0,0 +1,47 @@
+use hello::memo_cli::{parse_options, slugify, truncate, USAGE};
+
+// 메모 명령줄 클라이언트의 옵션 파싱과 출력 도우미
+//   cargo test --test memo_cli
+
+fn args(raw: &[&str]) -> Vec<String> {
+    raw.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn parse_options_splits_flags_from_command() {
+    let options = parse_options(args(&["--url", "http://memo.test:9000/", "--json", "export", "--format", "md", "--out", "./memos", "--token", "t"])).unwrap();
+    assert_eq!(options.url, "http://memo.test:9000");
+    assert_eq!(options.token.as_deref(), Some("t"));
+    assert!(options.json);
+    assert_eq!(options.format, "md");
+    assert_eq!(options.out.as_deref(), Some("./memos"));
+    assert_eq!(options.args, ["export"]);
+
+    // 옵션은 명령 앞뒤 어디에 있어도 되고, 나머지는 순서대로 남는다
+    let options = parse_options(args(&["new", "회의록", "--json"])).unwrap();
+    assert_eq!(options.args, ["new", "회의록"]);
+    assert_eq!(options.format, "json");
+}
+
+#[test]
+fn parse_options_rejects_missing_values_and_prints_usage() {
+    assert_eq!(parse_options(args(&["list", "--url"])).err().unwrap().to_string(), "--url 에 값이 필요합니다");
+    assert_eq!(parse_options(args(&["--help"])).err().unwrap().to_string(), USAGE);
+}
+
+#[test]
+fn truncate_keeps_first_line_within_width() {
+    assert_eq!(truncate("짧은 제목", 30), "짧은 제목");
+    assert_eq!(truncate("첫 줄\n둘째 줄", 30), "첫 줄");
+    assert_eq!(truncate("", 30), "");
+    // 바이트가 아니라 문자 수로 자르므로 한글이 깨지지 않는다
+    assert_eq!(truncate("가나다라마바사", 5), "가나다라…");
+    assert_eq!(truncate("가나다라마", 5), "가나다라마");
+}
+
+#[test]
+fn slugify_replaces_separators() {
+    assert_eq!(slugify("회의록 2024/01"), "회의록-2024-01");
+    assert_eq!(slugify("  ../../etc/passwd "), "etc-passwd");
+    assert_eq!(slugify("?!"), "");
+}
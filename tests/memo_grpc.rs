@@ -0,0 +1,101 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use futures_util::StreamExt;
+use hello::memo::pb::memo_service_client::MemoServiceClient;
+use hello::memo::pb::{ChangeKind, CreateMemoRequest, DeleteMemoRequest, GetMemoRequest, ListMemosRequest, UpdateMemoRequest, WatchMemosRequest};
+use hello::memo::{self, AppState};
+use std::time::Duration;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Channel, Server};
+use tonic::Code;
+
+// 메모 gRPC 서비스 통합 테스트 (빈 포트에 tonic 서버를 띄우고 생성된 클라이언트로 호출)
+//   cargo test --test memo_grpc
+
+// data 를 쓰는 gRPC 서버를 띄우고 연결한 클라이언트를 돌려준다
+async fn grpc_client(data: web::Data<AppState>) -> MemoServiceClient<Channel> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let incoming = TcpIncoming::from_listener(listener, true, None).unwrap();
+    tokio::spawn(Server::builder().add_service(memo::grpc_service(data)).serve_with_incoming(incoming));
+
+    MemoServiceClient::connect(format!("http://{}", addr)).await.unwrap()
+}
+
+fn create_request(title: &str, content: &str) -> CreateMemoRequest {
+    CreateMemoRequest { title: title.to_string(), content: content.to_string(), ..Default::default() }
+}
+
+#[actix_web::test]
+async fn create_get_list_update_delete_memo() {
+    let data = web::Data::new(AppState::default());
+    let mut client = grpc_client(data.clone()).await;
+
+    let memo = client.create_memo(create_request("장보기", "우유")).await.unwrap().into_inner();
+    assert_eq!(memo.title, "장보기");
+    assert!(memo.created_at.is_some());
+
+    // REST 와 같은 저장소를 쓴다
+    let app = test::init_service(App::new().app_data(data.clone()).configure(memo::configure_routes)).await;
+    let res = test::call_service(&app, test::TestRequest::get().uri(&format!("/memos/{}", memo.id)).to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let fetched = client.get_memo(GetMemoRequest { id: memo.id }).await.unwrap().into_inner();
+    assert_eq!(fetched.content, "우유");
+
+    client.create_memo(create_request("회의록", "주간 회의")).await.unwrap();
+    let list = client.list_memos(ListMemosRequest { q: "회의".to_string(), ..Default::default() }).await.unwrap().into_inner();
+    assert_eq!(list.total_count, 1);
+    assert_eq!(list.memos[0].title, "회의록");
+
+    let update = UpdateMemoRequest { id: memo.id, title: "장보기".to_string(), content: "우유, 계란".to_string(), ..Default::default() };
+    let updated = client.update_memo(update).await.unwrap().into_inner();
+    assert_eq!(updated.content, "우유, 계란");
+
+    let deleted = client.delete_memo(DeleteMemoRequest { id: memo.id }).await.unwrap().into_inner();
+    assert_eq!(deleted.content, "우유, 계란");
+
+    let status = client.get_memo(GetMemoRequest { id: memo.id }).await.unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+    let status = client.update_memo(UpdateMemoRequest { id: 999, ..Default::default() }).await.unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[actix_web::test]
+async fn invalid_requests_are_rejected() {
+    let data = web::Data::new(AppState::default());
+    let mut client = grpc_client(data).await;
+
+    let request = CreateMemoRequest { notebook_id: Some(999), ..create_request("노트북 없음", "") };
+    let status = client.create_memo(request).await.unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(status.message(), "노트북을 찾을 수 없습니다");
+
+    let request = CreateMemoRequest { due_at: Some(prost_types::Timestamp { seconds: 0, nanos: -1 }), ..create_request("잘못된 시각", "") };
+    let status = client.create_memo(request).await.unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[actix_web::test]
+async fn watch_memos_streams_changes() {
+    let data = web::Data::new(AppState::default());
+    let mut client = grpc_client(data).await;
+    let watched = client.create_memo(create_request("구독할 메모", "")).await.unwrap().into_inner();
+
+    // 응답이 오면 서버는 이미 변경 채널을 구독한 상태
+    let mut events = client.watch_memos(WatchMemosRequest { memo_id: Some(watched.id) }).await.unwrap().into_inner();
+
+    // 다른 메모의 변경은 걸러진다
+    client.create_memo(create_request("다른 메모", "")).await.unwrap();
+    client.update_memo(UpdateMemoRequest { id: watched.id, title: "제목 변경".to_string(), ..Default::default() }).await.unwrap();
+    client.delete_memo(DeleteMemoRequest { id: watched.id }).await.unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), events.next()).await.expect("이벤트가 오지 않았습니다").unwrap().unwrap();
+    assert_eq!(event.kind(), ChangeKind::Update);
+    assert_eq!(event.memo_id, watched.id);
+    assert_eq!(event.memo.unwrap().title, "제목 변경");
+
+    let event = tokio::time::timeout(Duration::from_secs(5), events.next()).await.expect("이벤트가 오지 않았습니다").unwrap().unwrap();
+    assert_eq!(event.kind(), ChangeKind::Delete);
+    assert!(event.memo.is_none());
+}
@@ -0,0 +1,215 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use async_trait::async_trait;
+use hello::memo::{self, sync_sheet, AppState, AuditLog, ContentCipher, GoogleSheet, MemorySheet, SheetStore, SyncError, SyncReport};
+use hello::sheets::{InMemorySheets, SheetsBackend};
+use serde_json::{json, Value};
+
+// 메모 ↔ 시트 동기화 테스트 (구글 시트 대신 메모리 시트 사용)
+//   cargo test --test memo_sync
+
+// 같은 AppState 를 쓰는 메모 API 서비스
+macro_rules! memo_app {
+    ($data:expr) => {
+        test::init_service(App::new().app_data($data.clone()).configure(memo::configure_routes)).await
+    };
+}
+
+macro_rules! send {
+    ($app:expr, $req:expr) => {{
+        let res = test::call_service(&$app, $req.to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        test::read_body_json::<Value, _>(res).await
+    }};
+}
+
+// 행에서 (id, 제목, 본문) 만
+fn rows(sheet: &MemorySheet) -> Vec<(String, String, String)> {
+    sheet.rows.iter().skip(1).map(|row| (row[0].clone(), row[1].clone(), row[2].clone())).collect()
+}
+
+fn row_index(sheet: &MemorySheet, id: u64) -> usize {
+    sheet.rows.iter().position(|row| row[0] == id.to_string()).unwrap()
+}
+
+// fail_writes 가 true 인 동안 쓰기에 실패하는 시트
+#[derive(Default)]
+struct FlakySheet {
+    sheet: MemorySheet,
+    fail_writes: bool,
+}
+
+#[async_trait]
+impl SheetStore for FlakySheet {
+    async fn read_rows(&self) -> Result<Vec<Vec<String>>, SyncError> {
+        self.sheet.read_rows().await
+    }
+
+    async fn write_rows(&mut self, rows: Vec<Vec<String>>) -> Result<(), SyncError> {
+        if self.fail_writes {
+            return Err("시트에 쓸 수 없습니다".into());
+        }
+        self.sheet.write_rows(rows).await
+    }
+}
+
+#[actix_web::test]
+async fn pushes_memos_to_sheet() {
+    let data = web::Data::new(AppState::default());
+    let app = memo_app!(data);
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "첫 메모", "content": "본문" })));
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "둘째", "content": "" })));
+
+    let mut sheet = MemorySheet::default();
+    let report = sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(report, SyncReport { pushed: 2, ..Default::default() });
+    assert_eq!(sheet.rows[0], ["id", "title", "content", "created_at", "updated_at", "sync_hash"]);
+    assert_eq!(rows(&sheet), [("0".into(), "첫 메모".into(), "본문".into()), ("1".into(), "둘째".into(), String::new())]);
+
+    // 바뀐 것이 없으면 아무 일도 하지 않는다
+    assert_eq!(sync_sheet(&data, &mut sheet).await.unwrap(), SyncReport::default());
+
+    // 서버에서 지운 메모는 시트에서도 빠진다
+    let res = test::call_service(&app, test::TestRequest::delete().uri("/memos/0").to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(rows(&sheet), [("1".into(), "둘째".into(), String::new())]);
+}
+
+#[actix_web::test]
+async fn pulls_sheet_edits_and_new_rows() {
+    let data = web::Data::new(AppState::default());
+    let app = memo_app!(data);
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "회의", "content": "안건", "due_at": "2030-01-01T00:00:00Z" })));
+
+    let mut sheet = MemorySheet::default();
+    sync_sheet(&data, &mut sheet).await.unwrap();
+
+    // 시트에서 본문을 고치고 새 행을 추가
+    let i = row_index(&sheet, 0);
+    sheet.rows[i][2] = "안건 - 수정".to_string();
+    sheet.rows.push(vec![String::new(), "시트에서 만든 메모".to_string(), "내용".to_string()]);
+
+    let report = sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(report, SyncReport { pulled: 1, created: 1, ..Default::default() });
+
+    let memo = send!(app, test::TestRequest::get().uri("/memos/0"));
+    assert_eq!(memo["content"], "안건 - 수정");
+    // 시트에 없는 필드는 그대로
+    assert_eq!(memo["due_at"], "2030-01-01T00:00:00Z");
+
+    let memo = send!(app, test::TestRequest::get().uri("/memos/1"));
+    assert_eq!(memo["title"], "시트에서 만든 메모");
+
+    // 새 행에는 ID 가 채워진다
+    assert_eq!(rows(&sheet), [("0".into(), "회의".into(), "안건 - 수정".into()), ("1".into(), "시트에서 만든 메모".into(), "내용".into())]);
+    assert_eq!(sync_sheet(&data, &mut sheet).await.unwrap(), SyncReport::default());
+}
+
+#[actix_web::test]
+async fn failed_write_does_not_duplicate_new_rows() {
+    let data = web::Data::new(AppState::default());
+    let app = memo_app!(data);
+
+    let mut sheet = FlakySheet { fail_writes: true, ..Default::default() };
+    sheet.sheet.rows.push(vec![String::new(), "시트에서 만든 메모".to_string(), "내용".to_string()]);
+
+    // 시트에 ID 를 쓰지 못했으면 메모도 만들지 않는다
+    assert!(sync_sheet(&data, &mut sheet).await.is_err());
+    assert_eq!(send!(app, test::TestRequest::get().uri("/memos")), json!([]));
+    assert_eq!(sheet.sheet.rows[0][0], "");
+
+    sheet.fail_writes = false;
+    let report = sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(report, SyncReport { created: 1, ..Default::default() });
+    assert_eq!(sync_sheet(&data, &mut sheet).await.unwrap(), SyncReport::default());
+
+    let memos = send!(app, test::TestRequest::get().uri("/memos"));
+    assert_eq!(memos.as_array().unwrap().len(), 1);
+    let id = memos[0]["id"].as_u64().unwrap();
+    assert_eq!(rows(&sheet.sheet), [(id.to_string(), "시트에서 만든 메모".into(), "내용".into())]);
+}
+
+#[actix_web::test]
+async fn encrypted_memos_are_not_synced() {
+    // 32바이트 0 키
+    let cipher = ContentCipher::new(&format!("k1:{}=", "A".repeat(43)), &[]).unwrap();
+    let data = web::Data::new(AppState::new(AuditLog::in_memory(), Some(cipher)));
+    let app = memo_app!(data);
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "비밀", "content": "평문으로 나가면 안 되는 본문" })));
+
+    let mut sheet = MemorySheet::default();
+    sheet.rows.push(vec![String::new(), "시트에서 만든 메모".to_string(), "내용".to_string()]);
+    let error = sync_sheet(&data, &mut sheet).await.unwrap_err();
+    assert!(error.to_string().contains("암호화"), "{}", error);
+
+    // 시트도 메모도 그대로
+    assert_eq!(sheet.rows.len(), 1);
+    let memos = send!(app, test::TestRequest::get().uri("/memos"));
+    assert_eq!(memos.as_array().unwrap().len(), 1);
+}
+
+#[actix_web::test]
+async fn conflicting_edits_keep_memo() {
+    let data = web::Data::new(AppState::default());
+    let app = memo_app!(data);
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "할 일", "content": "처음" })));
+
+    let mut sheet = MemorySheet::default();
+    sync_sheet(&data, &mut sheet).await.unwrap();
+
+    // 마지막 동기화 뒤 양쪽에서 모두 수정
+    send!(app, test::TestRequest::put().uri("/memos/0").set_json(json!({ "title": "할 일", "content": "서버에서 수정" })));
+    let i = row_index(&sheet, 0);
+    sheet.rows[i][2] = "시트에서 수정".to_string();
+
+    let report = sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(report, SyncReport { pushed: 1, conflicts: vec![0], ..Default::default() });
+
+    let memo = send!(app, test::TestRequest::get().uri("/memos/0"));
+    assert_eq!(memo["content"], "서버에서 수정");
+    assert_eq!(rows(&sheet), [("0".into(), "할 일".into(), "서버에서 수정".into())]);
+
+    // 시트에서만 고친 것은 충돌이 아니다
+    let i = row_index(&sheet, 0);
+    sheet.rows[i][1] = "할 일 (시트)".to_string();
+    let report = sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(report, SyncReport { pulled: 1, ..Default::default() });
+}
+
+#[actix_web::test]
+async fn syncs_through_sheets_backend() {
+    let data = web::Data::new(AppState::default());
+    let app = memo_app!(data);
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "백엔드", "content": "본문" })));
+
+    let backend = InMemorySheets::new();
+    let spreadsheet_id = backend.create_spreadsheet("메모").await.unwrap();
+    backend.add_sheet(&spreadsheet_id, "memos").await.unwrap();
+
+    // 복제한 백엔드도 같은 스프레드시트를 본다
+    let mut sheet = GoogleSheet::new(backend.clone(), &spreadsheet_id, "memos");
+    assert_eq!(sync_sheet(&data, &mut sheet).await.unwrap(), SyncReport { pushed: 1, ..Default::default() });
+
+    let rows = backend.sheet_rows(&spreadsheet_id, "memos").unwrap();
+    assert_eq!(rows[0][0], "id");
+    assert_eq!(rows[1][..3], ["0", "백엔드", "본문"]);
+
+    // 시트에서 본문을 고치면 메모에 반영된다
+    backend.update_values(&spreadsheet_id, "memos!C2", vec![vec!["시트에서 수정".to_string()]]).await.unwrap();
+    assert_eq!(sync_sheet(&data, &mut sheet).await.unwrap(), SyncReport { pulled: 1, ..Default::default() });
+    let memo = send!(app, test::TestRequest::get().uri("/memos/0"));
+    assert_eq!(memo["content"], "시트에서 수정");
+
+    // 시트를 지우지 않고 한 번에 덮어쓰므로, 줄어든 만큼의 행은 빈 값으로 덮여 사라진다
+    send!(app, test::TestRequest::post().uri("/memos").set_json(json!({ "title": "둘째", "content": "" })));
+    sync_sheet(&data, &mut sheet).await.unwrap();
+    assert_eq!(backend.sheet_rows(&spreadsheet_id, "memos").unwrap().len(), 3);
+
+    let res = test::call_service(&app, test::TestRequest::delete().uri("/memos/0").to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    sync_sheet(&data, &mut sheet).await.unwrap();
+    let rows = backend.sheet_rows(&spreadsheet_id, "memos").unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1][..3], ["1", "둘째", ""]);
+}
@@ -0,0 +1,83 @@
+use chrono::NaiveDate;
+use hello::sheets::{append_sheet_rows, read_sheet_rows, InMemorySheets, SheetRow, SheetsBackend, SheetsError, Task, PROJECT_SHEETS};
+
+// #[derive(SheetRow)] 테스트 (헤더, 행 쓰기/읽기)
+//   cargo test --test sheet_row
+
+#[derive(Debug, PartialEq, SheetRow)]
+struct Expense {
+    #[sheet(order = 2)]
+    amount: u32,
+    #[sheet(rename = "Item")]
+    name: String,
+    #[sheet(order = 0, format = "%Y/%m/%d")]
+    paid_on: NaiveDate,
+    approved: bool,
+    memo_text: Option<String>,
+}
+
+fn expense(amount: u32, name: &str, paid_on: (i32, u32, u32), approved: bool, memo_text: Option<&str>) -> Expense {
+    Expense { amount, name: name.to_string(), paid_on: NaiveDate::from_ymd_opt(paid_on.0, paid_on.1, paid_on.2).unwrap(), approved, memo_text: memo_text.map(str::to_string) }
+}
+
+// 다른 이름으로 가져온 크레이트 경로
+use hello as sheets_crate;
+
+#[derive(Debug, PartialEq, SheetRow)]
+#[sheet_row(crate = "sheets_crate")]
+struct Renamed {
+    title: String,
+}
+
+#[test]
+fn crate_path_can_be_overridden() {
+    assert_eq!(Renamed::HEADERS, ["Title"]);
+    assert_eq!(Renamed { title: "제목".to_string() }.to_row(), ["제목"]);
+}
+
+#[test]
+fn headers_follow_rename_and_order() {
+    // order 가 없는 필드는 선언 순서 번호 (name 1, approved 3, memo_text 4)
+    assert_eq!(Expense::HEADERS, ["Paid_On", "Item", "Amount", "Approved", "Memo_Text"]);
+    assert_eq!(expense(1200, "서버", (2024, 3, 1), true, None).to_row(), ["2024/03/01", "서버", "1200", "TRUE", ""]);
+
+    assert_eq!(Task::HEADERS, PROJECT_SHEETS[1].1);
+    assert_eq!(PROJECT_SHEETS[0].1[0], "Project_ID");
+}
+
+#[tokio::test]
+async fn append_and_read_rows() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("경비").await.unwrap();
+    sheets.create_sheet_with_headers(&id, "Expenses", Expense::HEADERS.iter().map(|h| h.to_string()).collect()).await.unwrap();
+
+    let expenses = vec![expense(1200, "서버", (2024, 3, 1), true, Some("연간")), expense(30, "도메인", (2024, 1, 1), false, None)];
+    append_sheet_rows(&sheets, &id, "Expenses!A:E", &expenses).await.unwrap();
+    assert_eq!(read_sheet_rows::<Expense, _>(&sheets, &id, "Expenses").await.unwrap(), expenses);
+
+    // 시트에서 사람이 고친 값도 읽는다 (다른 날짜 표기, 천 단위 쉼표)
+    sheets.update_values(&id, "Expenses!A4:D4", vec![vec!["2024-02-01".to_string(), "장비".to_string(), "1,500".to_string(), "yes".to_string()]]).await.unwrap();
+    let read = read_sheet_rows::<Expense, _>(&sheets, &id, "Expenses").await.unwrap();
+    assert_eq!(read[2], expense(1500, "장비", (2024, 2, 1), true, None));
+
+    sheets.update_values(&id, "Expenses!C3", vec![vec!["무료".to_string()]]).await.unwrap();
+    let SheetsError::InvalidRows(errors) = read_sheet_rows::<Expense, _>(&sheets, &id, "Expenses").await.unwrap_err() else { panic!() };
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "C3 (Amount) 값 \"무료\": 숫자가 아닙니다");
+}
+
+#[tokio::test]
+async fn columns_are_found_by_header() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("경비").await.unwrap();
+
+    // 열 순서가 달라도 헤더 이름으로 찾고, 없는 Option 열은 None
+    let rows = [["item", "Approved", "Amount", "Paid On"], ["서버", "FALSE", "10", "2024/05/05"]];
+    sheets.update_values(&id, "Sheet1!A1", rows.iter().map(|row| row.iter().map(|cell| cell.to_string()).collect()).collect()).await.unwrap();
+    assert_eq!(read_sheet_rows::<Expense, _>(&sheets, &id, "Sheet1").await.unwrap(), [expense(10, "서버", (2024, 5, 5), false, None)]);
+
+    // 필수 열이 없으면 행 에러
+    sheets.clear_values(&id, "Sheet1!C:C").await.unwrap();
+    let SheetsError::InvalidRows(errors) = read_sheet_rows::<Expense, _>(&sheets, &id, "Sheet1").await.unwrap_err() else { panic!() };
+    assert_eq!(errors[0].to_string(), "2행: Amount 열이 없습니다");
+}
@@ -0,0 +1,472 @@
+use chrono::NaiveDate;
+use google_sheets4::api::{AddSheetRequest, GridRange, Request, SheetProperties};
+use hello::sheets::{apply_plan, create_project_sheets, date_cell, plan_workbook, provision_workbook, read_rows, read_tasks, ColumnType, InMemorySheets, SheetsBackend, SheetsError, WorkbookSchema, PROJECT_SHEETS};
+use serde::Deserialize;
+
+// 시트 백엔드 테스트 (구글 API 대신 메모리 스프레드시트 사용)
+//   cargo test --test sheets
+
+fn add_sheet(title: &str) -> Request {
+    Request { add_sheet: Some(AddSheetRequest { properties: Some(SheetProperties { title: Some(title.to_string()), ..Default::default() }) }), ..Default::default() }
+}
+
+fn row(cells: &[&str]) -> Vec<String> {
+    cells.iter().map(|cell| cell.to_string()).collect()
+}
+
+#[tokio::test]
+async fn a1_ranges_update_get_and_clear() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("테스트").await.unwrap();
+    sheets.add_sheet(&id, "Data").await.unwrap();
+
+    sheets.update_values(&id, "Data!A1:B2", vec![row(&["이름", "점수"]), row(&["홍길동", "100"])]).await.unwrap();
+    // 셀 하나만 주면 그 셀부터 값 크기만큼
+    sheets.update_values(&id, "Data!C1", vec![row(&["비고"]), row(&["x"]), row(&["y"])]).await.unwrap();
+
+    // 범위를 넘는 값은 쓰지 않는다
+    let err = sheets.update_values(&id, "Data!A1:B2", vec![row(&["a", "b", "c"])]).await.unwrap_err();
+    assert!(matches!(err, SheetsError::BadRequest(_)), "{}", err);
+
+    assert_eq!(sheets.get_values(&id, "Data!A:A").await.unwrap(), [row(&["이름"]), row(&["홍길동"])]);
+    assert_eq!(sheets.get_values(&id, "Data!B2:C3").await.unwrap(), [row(&["100", "x"]), row(&["", "y"])]);
+    assert_eq!(sheets.get_values(&id, "'Data'!C3").await.unwrap(), [row(&["y"])]);
+
+    sheets.clear_values(&id, "Data!C:C").await.unwrap();
+    assert_eq!(sheets.sheet_rows(&id, "Data").unwrap(), [row(&["이름", "점수"]), row(&["홍길동", "100"])]);
+
+    // 없는 시트, 없는 스프레드시트
+    assert!(matches!(sheets.get_values(&id, "Nope!A1").await, Err(SheetsError::BadRequest(_))));
+    assert!(matches!(sheets.get_values("missing", "Data!A1").await, Err(SheetsError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn append_goes_below_table() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("테스트").await.unwrap();
+    sheets.add_sheet(&id, "test_sheet").await.unwrap();
+
+    let result = sheets.append_values(&id, "test_sheet!A1:B2", vec![row(&["이름", "점수"]), row(&["홍길동", "100"])]).await.unwrap();
+    assert_eq!(result.table_range, None);
+    assert_eq!(result.updates.unwrap().updated_range.as_deref(), Some("test_sheet!A1:B2"));
+
+    // 범위의 행 끝을 넘어 표 아래에 이어 붙인다
+    let result = sheets.append_values(&id, "test_sheet!A1:B2", vec![row(&["임꺽정", "90"])]).await.unwrap();
+    assert_eq!(result.table_range.as_deref(), Some("test_sheet!A1:B2"));
+    assert_eq!(result.updates.unwrap().updated_range.as_deref(), Some("test_sheet!A3:B3"));
+
+    sheets.append_values(&id, "test_sheet!A:C", vec![row(&["2024-01-01", "텍스트 추출", "본문"])]).await.unwrap();
+    assert_eq!(sheets.sheet_rows(&id, "test_sheet").unwrap().len(), 4);
+    assert_eq!(sheets.sheet_rows(&id, "test_sheet").unwrap()[3], row(&["2024-01-01", "텍스트 추출", "본문"]));
+}
+
+#[tokio::test]
+async fn create_project_sheets_writes_headers() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("프로젝트").await.unwrap();
+
+    let created = create_project_sheets(&sheets, &id, "Alpha").await.unwrap();
+    assert_eq!(created, ["Project_Overview_Alpha", "Task_Management_Alpha", "Roles_Responsibilities_Alpha"]);
+    assert_eq!(sheets.sheet_titles(&id), ["Sheet1", "Project_Overview_Alpha", "Task_Management_Alpha", "Roles_Responsibilities_Alpha"]);
+
+    for ((_, headers), title) in PROJECT_SHEETS.iter().zip(&created) {
+        assert_eq!(sheets.sheet_rows(&id, title).unwrap(), [row(headers)]);
+    }
+
+    // 같은 프로젝트를 다시 만들어도 아무것도 바뀌지 않는다
+    let requests = sheets.requests(&id).len();
+    assert!(create_project_sheets(&sheets, &id, "Alpha").await.unwrap().is_empty());
+    assert_eq!(sheets.sheet_titles(&id).len(), 4);
+    assert_eq!(sheets.requests(&id).len(), requests);
+}
+
+#[tokio::test]
+async fn batch_update_is_atomic() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("테스트").await.unwrap();
+
+    sheets.create_sheet(&id, "Tasks", row(&["ID", "Title", "Status"])).await.unwrap();
+    assert_eq!(sheets.sheet_titles(&id), ["Sheet1", "Tasks"]);
+    assert_eq!(sheets.requests(&id).len(), 3);
+
+    // 두 번째 요청이 실패하면 첫 번째 시트도 만들어지지 않는다
+    let err = sheets.batch_update(&id, vec![add_sheet("Other"), add_sheet("Tasks")]).await.unwrap_err();
+    assert!(matches!(err, SheetsError::BadRequest(_)), "{}", err);
+    assert_eq!(sheets.sheet_titles(&id), ["Sheet1", "Tasks"]);
+    assert_eq!(sheets.requests(&id).len(), 3);
+
+    let replies = sheets.batch_update(&id, vec![add_sheet("Other")]).await.unwrap();
+    let properties = replies[0].add_sheet.as_ref().and_then(|reply| reply.properties.as_ref()).unwrap();
+    assert_eq!(properties.sheet_id, Some(2));
+    assert_eq!(properties.index, Some(2));
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Level {
+    High,
+    Low,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Budget {
+    name: String,
+    amount: u32,
+    ratio: Option<f64>,
+    approved: bool,
+    level: Option<Level>,
+    #[serde(deserialize_with = "date_cell")]
+    due: NaiveDate,
+}
+
+#[tokio::test]
+async fn create_sheet_targets_the_new_sheet() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("테스트").await.unwrap();
+
+    // 첫 시트(Sheet1, ID 0) 가 아니라 새 시트에 헤더 수만큼 필터와 고정 행
+    let tasks_id = sheets.create_sheet(&id, "Tasks", row(&["ID", "Title", "Status"])).await.unwrap();
+    let members_id = sheets.create_sheet(&id, "Members", row(&["Name", "Email"])).await.unwrap();
+    assert_eq!((tasks_id, members_id), (1, 2));
+    assert!(sheets.basic_filter(&id, "Sheet1").is_none());
+
+    let filter = sheets.basic_filter(&id, "Tasks").unwrap();
+    assert_eq!((filter.sheet_id, filter.start_column_index, filter.end_column_index), (Some(1), Some(0), Some(3)));
+    assert_eq!(sheets.basic_filter(&id, "Members").unwrap().end_column_index, Some(2));
+    assert_eq!(sheets.sheet_rows(&id, "Members").unwrap(), [row(&["Name", "Email"])]);
+
+    let frozen = sheets.requests(&id).into_iter().filter_map(|request| request.update_sheet_properties).map(|request| request.properties.unwrap()).collect::<Vec<_>>();
+    assert_eq!(frozen.iter().map(|properties| properties.sheet_id).collect::<Vec<_>>(), [Some(1), Some(2)]);
+    assert_eq!(frozen[0].grid_properties.as_ref().unwrap().frozen_row_count, Some(1));
+
+    assert!(matches!(sheets.create_sheet(&id, "Empty", Vec::new()).await, Err(SheetsError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn read_tasks_from_project_sheet() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("프로젝트").await.unwrap();
+    create_project_sheets(&sheets, &id, "Alpha").await.unwrap();
+
+    sheets
+        .append_values(
+            &id,
+            "Task_Management_Alpha!A:K",
+            vec![
+                row(&["P1", "T1", "", "설계", "", "High", "홍길동", "", "2024-01-02", "2024. 2. 1.", "진행"]),
+                row(&[]),
+                row(&["P1", "T2", "T1", "구현"]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let tasks = read_tasks(&sheets, &id, "Alpha").await.unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].task_id, "T1");
+    assert_eq!(tasks[0].parent_task_id, None);
+    assert_eq!(tasks[0].assignee.as_deref(), Some("홍길동"));
+    assert_eq!(tasks[0].start_date, NaiveDate::from_ymd_opt(2024, 1, 2));
+    assert_eq!(tasks[0].end_date, NaiveDate::from_ymd_opt(2024, 2, 1));
+    assert_eq!(tasks[1].parent_task_id.as_deref(), Some("T1"));
+    assert_eq!(tasks[1].start_date, None);
+}
+
+#[tokio::test]
+async fn read_rows_coerces_cells_and_reports_bad_ones() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("예산").await.unwrap();
+    sheets.add_sheet(&id, "Budget").await.unwrap();
+
+    // 헤더 순서는 필드 순서와 달라도 되고, 모르는 열은 무시한다
+    sheets
+        .update_values(
+            &id,
+            "Budget!B2",
+            vec![
+                row(&["Due", "Name", "Amount", "Ratio", "Approved", "Level", "Memo"]),
+                row(&["2024/03/01", "서버", "1,200", "0.5", "TRUE", "High", "x"]),
+                row(&["45292", "도메인", " 30 ", "", "no"]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let budgets: Vec<Budget> = read_rows(&sheets, &id, "Budget!B2:H").await.unwrap();
+    assert_eq!(
+        budgets,
+        [
+            Budget { name: "서버".to_string(), amount: 1200, ratio: Some(0.5), approved: true, level: Some(Level::High), due: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap() },
+            Budget { name: "도메인".to_string(), amount: 30, ratio: None, approved: false, level: None, due: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() },
+        ]
+    );
+
+    sheets.append_values(&id, "Budget!B2:H", vec![row(&["2024-13-01", "잘못된 날짜", "1", "", "yes"]), row(&["2024-01-01", "잘못된 금액", "많음", "", "yes"]), row(&["2024-01-01", "빈 값", "1"])]).await.unwrap();
+    let err = read_rows::<Budget, _>(&sheets, &id, "Budget!B2:H").await.unwrap_err();
+    let SheetsError::InvalidRows(errors) = err else { panic!("{}", err) };
+    assert_eq!(errors.len(), 3);
+    assert_eq!((errors[0].row, errors[0].cell.as_deref(), errors[0].header.as_deref(), errors[0].value.as_str()), (5, Some("B5"), Some("Due"), "2024-13-01"));
+    assert_eq!((errors[1].row, errors[1].cell.as_deref(), errors[1].header.as_deref(), errors[1].value.as_str()), (6, Some("D6"), Some("Amount"), "많음"));
+    assert_eq!((errors[2].cell.as_deref(), errors[2].header.as_deref(), errors[2].value.as_str()), (Some("F7"), Some("Approved"), ""));
+    assert_eq!(errors[2].to_string(), "F7 (Approved) 값 \"\": 값이 없습니다");
+
+    // 필수 열이 아예 없으면 행 단위 에러
+    sheets.update_values(&id, "Budget!D2", vec![row(&["Total"])]).await.unwrap();
+    let SheetsError::InvalidRows(errors) = read_rows::<Budget, _>(&sheets, &id, "Budget!B2:H").await.unwrap_err() else { panic!() };
+    assert_eq!((errors[0].row, errors[0].cell.as_deref()), (3, None));
+    assert!(errors[0].message.contains("amount"), "{}", errors[0].message);
+}
+
+#[test]
+fn project_schema_matches_row_structs() {
+    let schema = WorkbookSchema::project();
+    assert_eq!(schema.sheets.len(), PROJECT_SHEETS.len());
+
+    for (sheet, (prefix, headers)) in schema.sheets.iter().zip(PROJECT_SHEETS) {
+        assert_eq!(sheet.title("Alpha"), format!("{}_Alpha", prefix));
+        assert_eq!(sheet.headers(), *headers);
+    }
+}
+
+#[test]
+fn invalid_schemas_are_rejected() {
+    let invalid = [
+        "sheets = []",
+        "[[sheets]]\nname = \"A\"\ncolumns = [{ name = \"X\" }, { name = \"X\" }]",
+        "[[sheets]]\nname = \"A\"\ncolumns = [{ name = \"Due\", type = \"date\", values = [\"a\"] }]",
+        "[[sheets]]\nname = \"A\"\ncolumns = [{ name = \"X\", type = \"money\" }]",
+        "[[sheets]]\nname = \"A\"\ncolumns = [{ name = \"X\", format = \"0.00\" }]",
+        "[[sheets]]\nname = \"A\"\nheader_color = \"blue\"\ncolumns = [{ name = \"X\" }]",
+        "[[sheets]]\nname = \"A\"\ncolumns = [{ name = \"X\" }]\n[[sheets.highlights]]\nwhen = \"{Y} > 0\"",
+        "[[sheets]]\nname = \"A\"\nfrozen = 1\ncolumns = [{ name = \"X\" }]",
+    ];
+    for text in invalid {
+        assert!(matches!(WorkbookSchema::from_toml(text), Err(SheetsError::InvalidSchema(_))), "{}", text);
+    }
+}
+
+#[tokio::test]
+async fn provision_workbook_from_schema() {
+    let schema = WorkbookSchema::from_toml(
+        r#"
+        [[sheets]]
+        name = "Bugs_{project}"
+        frozen_rows = 2
+        filter = false
+        columns = [
+            { name = "ID", type = "number" },
+            { name = "Title" },
+            { name = "Severity", values = ["Low", "High"] },
+        ]
+
+        [[sheets]]
+        name = "Notes"
+        columns = [{ name = "Date", type = "date" }, { name = "Text", width = 300 }]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(schema.sheets[0].columns[0].kind, ColumnType::Number);
+    assert_eq!(schema.sheets[1].frozen_rows, 1);
+
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("버그").await.unwrap();
+    let plan = provision_workbook(&sheets, &id, &schema, "Alpha").await.unwrap();
+    assert_eq!(plan.created_sheets(), ["Bugs_Alpha", "Notes"]);
+    assert_eq!(sheets.sheet_rows(&id, "Bugs_Alpha").unwrap(), [row(&["ID", "Title", "Severity"])]);
+
+    // 설정 요청은 새 시트 ID 로 (Bugs_Alpha = 1, Notes = 2)
+    let requests = sheets.requests(&id);
+    let frozen = requests[1].update_sheet_properties.as_ref().unwrap().properties.as_ref().unwrap();
+    assert_eq!(frozen.sheet_id, Some(1));
+    assert_eq!(frozen.grid_properties.as_ref().unwrap().frozen_row_count, Some(2));
+
+    // ID 는 숫자만 받는다
+    let condition = requests[2].set_data_validation.as_ref().unwrap().rule.as_ref().unwrap().condition.as_ref().unwrap();
+    assert_eq!(condition.type_.as_deref(), Some("CUSTOM_FORMULA"));
+    assert_eq!(condition.values.as_ref().unwrap()[0].user_entered_value.as_deref(), Some("=ISNUMBER(A2)"));
+
+    let validation = requests[3].set_data_validation.as_ref().unwrap();
+    let range = validation.range.as_ref().unwrap();
+    assert_eq!((range.sheet_id, range.start_row_index, range.start_column_index, range.end_column_index), (Some(1), Some(1), Some(2), Some(3)));
+    let condition = validation.rule.as_ref().unwrap().condition.as_ref().unwrap();
+    assert_eq!(condition.type_.as_deref(), Some("ONE_OF_LIST"));
+    assert_eq!(condition.values.as_ref().unwrap().iter().map(|v| v.user_entered_value.clone().unwrap()).collect::<Vec<_>>(), ["Low", "High"]);
+    assert!(requests.iter().take(4).all(|request| request.set_basic_filter.is_none()));
+
+    // filter 기본값은 true, 범위는 헤더 수만큼
+    let filter = sheets.basic_filter(&id, "Notes").unwrap();
+    assert_eq!((filter.sheet_id, filter.end_column_index), (Some(2), Some(2)));
+
+    // 너비를 정한 열만 너비 요청 (Notes 의 Text 열)
+    let widths: Vec<_> = requests.iter().filter_map(|request| request.update_dimension_properties.as_ref()).collect();
+    assert_eq!(widths.len(), 1);
+    let range = widths[0].range.as_ref().unwrap();
+    assert_eq!((range.sheet_id, range.dimension.as_deref(), range.start_index, range.end_index), (Some(2), Some("COLUMNS"), Some(1), Some(2)));
+    assert_eq!(widths[0].properties.as_ref().unwrap().pixel_size, Some(300));
+}
+
+#[tokio::test]
+async fn project_columns_get_validation_and_formats() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("프로젝트").await.unwrap();
+    create_project_sheets(&sheets, &id, "Alpha").await.unwrap();
+
+    // Project_Overview_Alpha (시트 ID 1) 의 열 요청
+    let requests = sheets.requests(&id);
+    let column = |index: i32| {
+        let in_column = |range: &Option<GridRange>| range.as_ref().is_some_and(|range| range.sheet_id == Some(1) && range.start_column_index == Some(index));
+        let validation = requests.iter().filter_map(|request| request.set_data_validation.as_ref()).find(|request| in_column(&request.range));
+        let format = requests.iter().filter_map(|request| request.repeat_cell.as_ref()).find(|request| in_column(&request.range));
+        let condition = validation.and_then(|request| request.rule.clone()).and_then(|rule| rule.condition);
+        let number_format = format.and_then(|request| request.cell.clone()).and_then(|cell| cell.user_entered_format).and_then(|format| format.number_format);
+        (condition, number_format)
+    };
+
+    // Start_Date (D): 날짜만, yyyy-mm-dd
+    let (condition, format) = column(3);
+    assert_eq!(condition.unwrap().type_.as_deref(), Some("DATE_IS_VALID"));
+    let format = format.unwrap();
+    assert_eq!((format.type_.as_deref(), format.pattern.as_deref()), (Some("DATE"), Some("yyyy-mm-dd")));
+
+    // Budget (G): 숫자만, 통화 형식
+    let (condition, format) = column(6);
+    assert_eq!(condition.unwrap().values.unwrap()[0].user_entered_value.as_deref(), Some("=ISNUMBER(G2)"));
+    assert_eq!(format.unwrap().type_.as_deref(), Some("CURRENCY"));
+
+    // Status (I): 드롭다운, 형식 없음
+    let (condition, format) = column(8);
+    let condition = condition.unwrap();
+    assert_eq!(condition.type_.as_deref(), Some("ONE_OF_LIST"));
+    assert!(condition.values.unwrap().iter().any(|value| value.user_entered_value.as_deref() == Some("Done")));
+    assert!(format.is_none());
+
+    // Project_Name (B): 검증도 형식도 없다
+    assert!(matches!(column(1), (None, None)));
+}
+
+#[tokio::test]
+async fn project_sheets_get_header_style_banding_and_highlights() {
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("프로젝트").await.unwrap();
+    create_project_sheets(&sheets, &id, "Alpha").await.unwrap();
+
+    // Task_Management_Alpha (시트 ID 2) 에 붙은 요청만
+    let requests: Vec<_> = sheets
+        .requests(&id)
+        .into_iter()
+        .filter(|request| {
+            let ranges = [
+                request.repeat_cell.as_ref().and_then(|r| r.range.as_ref()),
+                request.add_banding.as_ref().and_then(|r| r.banded_range.as_ref()).and_then(|r| r.range.as_ref()),
+                request.add_conditional_format_rule.as_ref().and_then(|r| r.rule.as_ref()).and_then(|r| r.ranges.as_ref()).and_then(|r| r.first()),
+            ];
+            ranges.iter().flatten().any(|range| range.sheet_id == Some(2))
+        })
+        .collect();
+
+    // 헤더 행 전체: 굵게 + 배경색
+    let header = requests.iter().filter_map(|request| request.repeat_cell.as_ref()).find(|request| request.range.as_ref().unwrap().end_row_index == Some(1)).unwrap();
+    let format = header.cell.as_ref().unwrap().user_entered_format.as_ref().unwrap();
+    assert_eq!(format.text_format.as_ref().unwrap().bold, Some(true));
+    assert!(format.background_color.is_some());
+    assert_eq!(header.range.as_ref().unwrap().end_column_index, None);
+
+    // 데이터 행 줄무늬
+    let banding = requests.iter().find_map(|request| request.add_banding.as_ref()).unwrap().banded_range.as_ref().unwrap();
+    assert_eq!(banding.range.as_ref().unwrap().start_row_index, Some(1));
+
+    // 기한 지난 작업 (End_Date = J, Status = K) 은 빨갛게, 순서대로
+    let rules: Vec<_> = requests.iter().filter_map(|request| request.add_conditional_format_rule.as_ref()).collect();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules.iter().map(|rule| rule.index).collect::<Vec<_>>(), [Some(0), Some(1)]);
+    let overdue = rules[0].rule.as_ref().unwrap().boolean_rule.as_ref().unwrap();
+    let formula = overdue.condition.as_ref().unwrap().values.as_ref().unwrap()[0].user_entered_value.clone().unwrap();
+    assert_eq!(formula, "=AND($J2 <> \"\", DATEVALUE($J2) < TODAY(), $K2 <> \"Done\")");
+    let red = overdue.format.as_ref().unwrap().background_color.as_ref().unwrap();
+    assert_eq!(red.red, Some(244.0 / 255.0));
+}
+
+#[tokio::test]
+async fn provisioning_migrates_existing_sheets() {
+    let schema = WorkbookSchema::from_toml(
+        r#"
+        [[sheets]]
+        name = "Tasks"
+        columns = [{ name = "ID" }, { name = "Title" }, { name = "Owner", values = ["Kim", "Lee"] }, { name = "Status" }]
+
+        [[sheets]]
+        name = "Members"
+        columns = [{ name = "Name" }]
+        "#,
+    )
+    .unwrap();
+
+    // Tasks 는 이미 있다 (Name 은 Title 의 옛 이름, Old 는 스키마에 없는 열, Owner 가 빠짐)
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("작업").await.unwrap();
+    let tasks_id = sheets.create_sheet_with_headers(&id, "Tasks", vec!["ID".to_string(), "Name".to_string(), "Status".to_string(), "Old".to_string()]).await.unwrap();
+    sheets.append_values(&id, "Tasks!A:D", vec![row(&["1", "설계", "Todo", "x"])]).await.unwrap();
+
+    let plan = plan_workbook(&sheets, &id, &schema, "Alpha").await.unwrap();
+    assert_eq!(plan.sheets[0].added_columns, ["Owner"]);
+    assert_eq!(plan.sheets[0].renamed_columns, [("Name".to_string(), "Title".to_string())]);
+    assert_eq!(plan.sheets[0].removed_columns, ["Old"]);
+    assert_eq!(plan.created_sheets(), ["Members"]);
+    assert_eq!(
+        plan.to_string(),
+        "+ Tasks 에 열 추가: Owner\n\
+         ! Tasks 의 열 Name 은 스키마의 Title 로 이름이 바뀐 것 같습니다 (바꾸지 않음)\n\
+         ! Tasks 의 열 Old 은 스키마에 없습니다 (지우지 않음)\n\
+         + 시트 만들기 Members (1개 열)\n"
+    );
+
+    // 계획만 세우면 아무것도 바뀌지 않는다
+    assert_eq!(sheets.sheet_titles(&id), ["Sheet1", "Tasks"]);
+
+    apply_plan(&sheets, &id, &plan).await.unwrap();
+    assert_eq!(sheets.sheet_titles(&id), ["Sheet1", "Tasks", "Members"]);
+    assert_eq!(sheets.sheet_rows(&id, "Tasks").unwrap(), [row(&["ID", "Name", "Status", "Old", "Owner"]), row(&["1", "설계", "Todo", "x"])]);
+
+    // 필터는 늘어난 헤더까지, 드롭다운은 추가한 Owner 열(E) 에
+    assert_eq!(sheets.basic_filter(&id, "Tasks").unwrap().end_column_index, Some(5));
+    let requests = sheets.requests(&id);
+    let validation = requests.iter().find_map(|request| request.set_data_validation.as_ref()).unwrap();
+    assert_eq!(validation.range.as_ref().map(|range| (range.sheet_id, range.start_column_index)), Some((Some(tasks_id), Some(4))));
+
+    // 다시 계획하면 알릴 것만 남는다
+    let plan = plan_workbook(&sheets, &id, &schema, "Alpha").await.unwrap();
+    assert!(plan.is_empty());
+    assert_eq!(plan.sheets[0].removed_columns, ["Old"]);
+    assert!(plan.to_string().ends_with("= 바꿀 것이 없습니다\n"));
+}
+
+#[tokio::test]
+async fn sheet_titles_with_spaces_are_quoted() {
+    let schema = WorkbookSchema::from_toml(
+        r#"
+        [[sheets]]
+        name = "Tasks_{project}"
+        columns = [{ name = "ID" }, { name = "Title" }, { name = "Owner" }]
+        "#,
+    )
+    .unwrap();
+
+    let sheets = InMemorySheets::new();
+    let id = sheets.create_spreadsheet("작업").await.unwrap();
+
+    // 공백이 있는 시트 이름은 작은따옴표로 감싸야 한다
+    sheets.create_sheet_with_headers(&id, "Tasks_Alpha Team", vec!["ID".to_string(), "Title".to_string()]).await.unwrap();
+    assert!(matches!(sheets.get_values(&id, "Tasks_Alpha Team!1:1").await, Err(SheetsError::BadRequest(_))));
+    assert_eq!(sheets.get_values(&id, "'Tasks_Alpha Team'!1:1").await.unwrap(), [row(&["ID", "Title"])]);
+
+    let plan = plan_workbook(&sheets, &id, &schema, "Alpha Team").await.unwrap();
+    assert_eq!(plan.sheets[0].added_columns, ["Owner"]);
+    apply_plan(&sheets, &id, &plan).await.unwrap();
+    assert_eq!(sheets.sheet_rows(&id, "Tasks_Alpha Team").unwrap(), [row(&["ID", "Title", "Owner"])]);
+    assert!(plan_workbook(&sheets, &id, &schema, "Alpha Team").await.unwrap().is_empty());
+
+    // 프로젝트 시트도 같은 이름 규칙
+    assert_eq!(create_project_sheets(&sheets, &id, "Alpha Team").await.unwrap().len(), PROJECT_SHEETS.len());
+    sheets.append_values(&id, "'Task_Management_Alpha Team'!A:D", vec![row(&["P1", "T1", "", "설계"])]).await.unwrap();
+    let tasks = read_tasks(&sheets, &id, "Alpha Team").await.unwrap();
+    assert_eq!(tasks.len(), 1);
+}